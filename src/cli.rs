@@ -2,8 +2,9 @@
 //!
 //! Provides argument parsing and validation for the wordlist filtering tool.
 
+use crate::compress::Compression;
 use clap::{Parser, ValueEnum};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// High-performance wordlist filter for penetration testing
 ///
@@ -58,8 +59,13 @@ REGEX PATTERN EXAMPLES:
 )]
 pub struct Args {
     /// Input file or directory path
-    #[arg(short, long, required = true, value_name = "PATH")]
-    pub input: PathBuf,
+    #[arg(
+        short,
+        long,
+        required_unless_present_any = ["completions", "generate_man"],
+        value_name = "PATH"
+    )]
+    pub input: Option<PathBuf>,
 
     /// Output directory (default: current directory)
     #[arg(short, long, value_name = "DIR")]
@@ -73,6 +79,12 @@ pub struct Args {
     #[arg(short, long, value_name = "PATTERN")]
     pub pattern: Option<String>,
 
+    /// Demultiplex words into one file per matching builtin category
+    /// (complex_password.txt, digits_only.txt, ...) in a single pass, using
+    /// `filter::patterns::NAMED`
+    #[arg(long, default_value_t = false)]
+    pub categorize: bool,
+
     /// Combine all results into a single output file
     #[arg(long, default_value_t = false)]
     pub single_file: bool,
@@ -85,6 +97,26 @@ pub struct Args {
     #[arg(short, long, default_value_t = false)]
     pub recursive: bool,
 
+    /// Follow symlinks while scanning directories
+    #[arg(long, default_value_t = false)]
+    pub follow_symlinks: bool,
+
+    /// Exclude files whose relative path matches this glob (repeatable)
+    #[arg(long, value_name = "GLOB")]
+    pub exclude: Vec<String>,
+
+    /// Only include files whose relative path matches this glob (repeatable)
+    #[arg(long, value_name = "GLOB")]
+    pub include: Vec<String>,
+
+    /// Minimum file size to process (e.g., "1KB")
+    #[arg(long, value_name = "SIZE")]
+    pub min_size: Option<String>,
+
+    /// Maximum file size to process (e.g., "500MB")
+    #[arg(long, value_name = "SIZE")]
+    pub max_size: Option<String>,
+
     /// Number of threads (default: auto-detect)
     #[arg(short = 't', long, value_name = "NUM")]
     pub threads: Option<usize>,
@@ -97,6 +129,18 @@ pub struct Args {
     #[arg(long, value_name = "SIZE", default_value = "8GB")]
     pub memory_limit: String,
 
+    /// Deduplication mode: exact string storage, or a 64/128-bit hash
+    /// fingerprint to bound memory at the cost of a (tiny) collision risk
+    #[arg(long, value_enum, default_value_t = DedupMode::Exact)]
+    pub dedup_mode: DedupMode,
+
+    /// Path to the on-disk dedup database (default: a path under the OS temp
+    /// dir). Point this at a stable path to resume a previous run's dedup
+    /// state instead of starting fresh. Only used with `--dedup-strategy disk`
+    #[cfg(feature = "disk-dedup")]
+    #[arg(long, value_name = "PATH")]
+    pub disk_dedup_path: Option<PathBuf>,
+
     /// Disable deduplication (faster but may contain duplicates)
     #[arg(long, default_value_t = false)]
     pub no_dedup: bool,
@@ -132,6 +176,49 @@ pub struct Args {
     /// Sort output alphabetically
     #[arg(long, default_value_t = false)]
     pub sort: bool,
+
+    /// Temp directory for external merge sort run files (default: OS temp dir)
+    #[arg(long, value_name = "DIR")]
+    pub tempdir: Option<PathBuf>,
+
+    /// Compress output files (input compression is always auto-detected)
+    #[arg(long, value_enum, default_value_t = CompressOutput::None)]
+    pub compress: CompressOutput,
+
+    /// Reject words containing any literal substring from this file (one per line)
+    #[arg(long, value_name = "FILE")]
+    pub deny_list: Option<PathBuf>,
+
+    /// Keep only words containing at least one literal substring from this file (one per line)
+    #[arg(long, value_name = "FILE")]
+    pub allow_list: Option<PathBuf>,
+
+    /// Match --deny-list/--allow-list substrings case-insensitively
+    #[arg(long, default_value_t = false)]
+    pub substring_case_insensitive: bool,
+
+    /// Hashing algorithm backing the memory/bloom deduplicators: ahash
+    /// (DoS-resistant, default) or xxh3 (faster, for trusted local input)
+    #[arg(long, value_enum, default_value_t = HasherAlgo::Ahash)]
+    pub hasher: HasherAlgo,
+
+    /// Collapse near-duplicate words onto one canonical key before
+    /// deduplicating (the original spelling is still what gets written out)
+    #[arg(long, value_enum, default_value_t = NormalizeMode::None)]
+    pub normalize: NormalizeMode,
+
+    /// Emit a machine-readable JSON summary to stdout instead of the colored
+    /// report, suppressing the banner and progress bars (implies --quiet)
+    #[arg(long, default_value_t = false)]
+    pub json: bool,
+
+    /// Print a shell completion script for the given shell to stdout and exit
+    #[arg(long, value_enum, hide = true)]
+    pub completions: Option<clap_complete::Shell>,
+
+    /// Render a roff man page into DIR and exit
+    #[arg(long, value_name = "DIR", hide = true)]
+    pub generate_man: Option<PathBuf>,
 }
 
 /// Deduplication strategy for handling large datasets
@@ -141,11 +228,62 @@ pub enum DedupStrategy {
     Memory,
     /// Streaming with bloom filter (fast, probabilistic)
     Bloom,
+    /// Streaming with a self-tuning bloom filter that rotates before its
+    /// false-positive rate grows unbounded (for unattended/streaming runs)
+    RotatingBloom,
     /// Disk-based deduplication (slowest, unlimited size)
     #[cfg(feature = "disk-dedup")]
     Disk,
 }
 
+/// How deduplication identifies "the same" word
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum DedupMode {
+    /// Store the full word; exact, no collision risk (default)
+    Exact,
+    /// Store a 64-bit xxh3 fingerprint instead of the word; ~8 bytes/item,
+    /// but can rarely drop a genuinely-unique word on a hash collision
+    Hash64,
+    /// Store a 128-bit xxh3 fingerprint; ~16 bytes/item with a negligible
+    /// collision rate, recommended for huge inputs
+    Hash128,
+}
+
+/// Hashing algorithm for the memory/bloom deduplicator families
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum HasherAlgo {
+    /// ahash: DoS-resistant, randomly seeded per run (default)
+    Ahash,
+    /// xxh3: faster, unkeyed -- fine for trusted local wordlists
+    Xxh3,
+}
+
+/// Canonical-key normalization applied before deduplication, to collapse
+/// near-duplicate words (case variants, leet-speak substitutions, Unicode
+/// look-alikes) onto a single entry
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum NormalizeMode {
+    /// No normalization; dedup on the exact string (default)
+    None,
+    /// Case-fold only
+    CaseFold,
+    /// Case-fold plus common leet-speak substitutions (@/4->a, 3->e, 0->o, 1->i, $->s)
+    Leet,
+    /// Unicode NFC normalization, then case-fold
+    UnicodeNfc,
+    /// Unicode NFKC normalization, then case-fold
+    UnicodeNfkc,
+}
+
+/// Compression format to write output files in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum CompressOutput {
+    /// Plain, uncompressed text (default)
+    None,
+    Gzip,
+    Zstd,
+}
+
 impl Args {
     /// Parse the length specification into a list of lengths
     pub fn parse_lengths(&self) -> anyhow::Result<Option<Vec<usize>>> {
@@ -195,12 +333,22 @@ impl Args {
 
     /// Parse buffer size string to bytes
     pub fn parse_buffer_size(&self) -> anyhow::Result<usize> {
-        parse_size(&self.buffer_size)
+        parse_size(&self.buffer_size).map(|n| n as usize)
     }
 
     /// Parse memory limit string to bytes
     pub fn parse_memory_limit(&self) -> anyhow::Result<usize> {
-        parse_size(&self.memory_limit)
+        parse_size(&self.memory_limit).map(|n| n as usize)
+    }
+
+    /// Parse the minimum file size bound, if any, to bytes
+    pub fn parse_min_size(&self) -> anyhow::Result<Option<u64>> {
+        self.min_size.as_deref().map(parse_size).transpose()
+    }
+
+    /// Parse the maximum file size bound, if any, to bytes
+    pub fn parse_max_size(&self) -> anyhow::Result<Option<u64>> {
+        self.max_size.as_deref().map(parse_size).transpose()
     }
 
     /// Get output directory, defaulting to current directory
@@ -208,6 +356,20 @@ impl Args {
         self.output.clone().unwrap_or_else(|| PathBuf::from("."))
     }
 
+    /// Get temp directory for external sort run files, defaulting to the OS temp dir
+    pub fn get_tempdir(&self) -> PathBuf {
+        self.tempdir.clone().unwrap_or_else(std::env::temp_dir)
+    }
+
+    /// Map the `--compress` flag to the shared `Compression` type
+    pub fn compression(&self) -> Compression {
+        match self.compress {
+            CompressOutput::None => Compression::None,
+            CompressOutput::Gzip => Compression::Gzip,
+            CompressOutput::Zstd => Compression::Zstd,
+        }
+    }
+
     /// Parse file extensions to process
     pub fn get_extensions(&self) -> Vec<String> {
         self.extensions
@@ -216,28 +378,65 @@ impl Args {
             .filter(|s| !s.is_empty())
             .collect()
     }
+
+    /// Read the newline-separated substrings from `--deny-list`, if set
+    pub fn read_deny_list(&self) -> anyhow::Result<Option<Vec<String>>> {
+        self.deny_list.as_deref().map(read_substring_list).transpose()
+    }
+
+    /// Read the newline-separated substrings from `--allow-list`, if set
+    pub fn read_allow_list(&self) -> anyhow::Result<Option<Vec<String>>> {
+        self.allow_list.as_deref().map(read_substring_list).transpose()
+    }
 }
 
-/// Parse human-readable size string to bytes
-fn parse_size(size_str: &str) -> anyhow::Result<usize> {
-    let size_str = size_str.trim().to_uppercase();
-    
-    let (num_str, multiplier) = if size_str.ends_with("GB") {
-        (&size_str[..size_str.len()-2], 1024 * 1024 * 1024)
-    } else if size_str.ends_with("MB") {
-        (&size_str[..size_str.len()-2], 1024 * 1024)
-    } else if size_str.ends_with("KB") {
-        (&size_str[..size_str.len()-2], 1024)
-    } else if size_str.ends_with("B") {
-        (&size_str[..size_str.len()-1], 1)
-    } else {
-        (size_str.as_str(), 1)
+/// Read one literal substring per line from `path`, skipping blank lines
+fn read_substring_list(path: &Path) -> anyhow::Result<Vec<String>> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| anyhow::anyhow!("Failed to read substring list '{}': {}", path.display(), e))?;
+
+    Ok(content
+        .lines()
+        .map(|line| line.trim().to_string())
+        .filter(|line| !line.is_empty())
+        .collect())
+}
+
+/// Parse a human-readable size string (e.g. "64M", "1G", "512K", "2GiB",
+/// "500MB") to a byte count.
+///
+/// A bare number is bytes. A single-letter suffix (K/M/G) defaults to the
+/// binary multiplier (1024^n), matching most buffer/memory-size tools. An
+/// explicit "iB" suffix (KiB/MiB/GiB) is always binary too; an explicit "B"
+/// suffix without the "i" (KB/MB/GB) is decimal (1000^n), matching how
+/// storage/network sizes are usually advertised.
+fn parse_size(size_str: &str) -> anyhow::Result<u64> {
+    let trimmed = size_str.trim();
+    if trimmed.is_empty() {
+        anyhow::bail!("Size string is empty");
+    }
+
+    let split_at = trimmed
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(trimmed.len());
+    let (num_str, suffix) = trimmed.split_at(split_at);
+
+    let multiplier: u64 = match suffix.to_uppercase().as_str() {
+        "" | "B" => 1,
+        "K" | "KIB" => 1024,
+        "M" | "MIB" => 1024 * 1024,
+        "G" | "GIB" => 1024 * 1024 * 1024,
+        "KB" => 1_000,
+        "MB" => 1_000_000,
+        "GB" => 1_000_000_000,
+        other => anyhow::bail!("Unknown size suffix '{}' in '{}'", other, size_str),
     };
 
-    let num: usize = num_str.trim().parse()
+    let num: f64 = num_str
+        .parse()
         .map_err(|_| anyhow::anyhow!("Invalid size format: '{}'", size_str))?;
-    
-    Ok(num * multiplier)
+
+    Ok((num * multiplier as f64).round() as u64)
 }
 
 #[cfg(test)]
@@ -247,16 +446,25 @@ mod tests {
     #[test]
     fn test_parse_single_length() {
         let args = Args {
-            input: PathBuf::from("test.txt"),
+            input: Some(PathBuf::from("test.txt")),
             output: None,
             length: Some("8".to_string()),
             pattern: None,
+            categorize: false,
             single_file: false,
             output_name: "filtered_wordlist.txt".to_string(),
             recursive: false,
+            follow_symlinks: false,
+            exclude: vec![],
+            include: vec![],
+            min_size: None,
+            max_size: None,
             threads: None,
             dedup_strategy: DedupStrategy::Memory,
             memory_limit: "8GB".to_string(),
+            dedup_mode: DedupMode::Exact,
+            #[cfg(feature = "disk-dedup")]
+            disk_dedup_path: None,
             no_dedup: false,
             stats: false,
             quiet: false,
@@ -266,6 +474,16 @@ mod tests {
             extensions: "txt".to_string(),
             preserve_order: false,
             sort: false,
+            tempdir: None,
+            compress: CompressOutput::None,
+            deny_list: None,
+            allow_list: None,
+            substring_case_insensitive: false,
+            hasher: HasherAlgo::Ahash,
+            normalize: NormalizeMode::None,
+            json: false,
+            completions: None,
+            generate_man: None,
         };
         
         let lengths = args.parse_lengths().unwrap().unwrap();
@@ -275,16 +493,25 @@ mod tests {
     #[test]
     fn test_parse_multiple_lengths() {
         let args = Args {
-            input: PathBuf::from("test.txt"),
+            input: Some(PathBuf::from("test.txt")),
             output: None,
             length: Some("8,9,10".to_string()),
             pattern: None,
+            categorize: false,
             single_file: false,
             output_name: "filtered_wordlist.txt".to_string(),
             recursive: false,
+            follow_symlinks: false,
+            exclude: vec![],
+            include: vec![],
+            min_size: None,
+            max_size: None,
             threads: None,
             dedup_strategy: DedupStrategy::Memory,
             memory_limit: "8GB".to_string(),
+            dedup_mode: DedupMode::Exact,
+            #[cfg(feature = "disk-dedup")]
+            disk_dedup_path: None,
             no_dedup: false,
             stats: false,
             quiet: false,
@@ -294,6 +521,16 @@ mod tests {
             extensions: "txt".to_string(),
             preserve_order: false,
             sort: false,
+            tempdir: None,
+            compress: CompressOutput::None,
+            deny_list: None,
+            allow_list: None,
+            substring_case_insensitive: false,
+            hasher: HasherAlgo::Ahash,
+            normalize: NormalizeMode::None,
+            json: false,
+            completions: None,
+            generate_man: None,
         };
         
         let lengths = args.parse_lengths().unwrap().unwrap();
@@ -303,16 +540,25 @@ mod tests {
     #[test]
     fn test_parse_length_range() {
         let args = Args {
-            input: PathBuf::from("test.txt"),
+            input: Some(PathBuf::from("test.txt")),
             output: None,
             length: Some("8-12".to_string()),
             pattern: None,
+            categorize: false,
             single_file: false,
             output_name: "filtered_wordlist.txt".to_string(),
             recursive: false,
+            follow_symlinks: false,
+            exclude: vec![],
+            include: vec![],
+            min_size: None,
+            max_size: None,
             threads: None,
             dedup_strategy: DedupStrategy::Memory,
             memory_limit: "8GB".to_string(),
+            dedup_mode: DedupMode::Exact,
+            #[cfg(feature = "disk-dedup")]
+            disk_dedup_path: None,
             no_dedup: false,
             stats: false,
             quiet: false,
@@ -322,6 +568,16 @@ mod tests {
             extensions: "txt".to_string(),
             preserve_order: false,
             sort: false,
+            tempdir: None,
+            compress: CompressOutput::None,
+            deny_list: None,
+            allow_list: None,
+            substring_case_insensitive: false,
+            hasher: HasherAlgo::Ahash,
+            normalize: NormalizeMode::None,
+            json: false,
+            completions: None,
+            generate_man: None,
         };
         
         let lengths = args.parse_lengths().unwrap().unwrap();
@@ -329,9 +585,31 @@ mod tests {
     }
 
     #[test]
-    fn test_parse_size() {
-        assert_eq!(parse_size("64MB").unwrap(), 64 * 1024 * 1024);
-        assert_eq!(parse_size("8GB").unwrap(), 8 * 1024 * 1024 * 1024);
-        assert_eq!(parse_size("1024KB").unwrap(), 1024 * 1024);
+    fn test_parse_size_bare_bytes() {
+        assert_eq!(parse_size("1024").unwrap(), 1024);
+        assert_eq!(parse_size("1024B").unwrap(), 1024);
+    }
+
+    #[test]
+    fn test_parse_size_binary_suffixes() {
+        assert_eq!(parse_size("64M").unwrap(), 64 * 1024 * 1024);
+        assert_eq!(parse_size("1G").unwrap(), 1024 * 1024 * 1024);
+        assert_eq!(parse_size("512K").unwrap(), 512 * 1024);
+        assert_eq!(parse_size("2GiB").unwrap(), 2 * 1024 * 1024 * 1024);
+        assert_eq!(parse_size("64MiB").unwrap(), 64 * 1024 * 1024);
+    }
+
+    #[test]
+    fn test_parse_size_decimal_suffixes() {
+        assert_eq!(parse_size("500MB").unwrap(), 500_000_000);
+        assert_eq!(parse_size("1GB").unwrap(), 1_000_000_000);
+        assert_eq!(parse_size("2KB").unwrap(), 2_000);
+    }
+
+    #[test]
+    fn test_parse_size_rejects_bad_input() {
+        assert!(parse_size("").is_err());
+        assert!(parse_size("-5K").is_err());
+        assert!(parse_size("5XB").is_err());
     }
 }
@@ -0,0 +1,28 @@
+//! Shell completion scripts and man page generation, derived straight from
+//! the `Args` clap definition so they can never drift from the real flags.
+
+use clap::CommandFactory;
+use clap_complete::Shell;
+use std::path::Path;
+
+use crate::cli::Args;
+
+/// Print a completion script for `shell` to stdout.
+pub fn print_completions(shell: Shell) {
+    let mut cmd = Args::command();
+    let name = cmd.get_name().to_string();
+    clap_complete::generate(shell, &mut cmd, name, &mut std::io::stdout());
+}
+
+/// Render a roff man page for the CLI into `dir`, named after the binary.
+pub fn generate_man(dir: &Path) -> anyhow::Result<()> {
+    std::fs::create_dir_all(dir)?;
+
+    let cmd = Args::command();
+    let man = clap_mangen::Man::new(cmd);
+    let mut buffer = Vec::new();
+    man.render(&mut buffer)?;
+
+    std::fs::write(dir.join("wordlist-filter.1"), buffer)?;
+    Ok(())
+}
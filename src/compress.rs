@@ -0,0 +1,140 @@
+//! Transparent compression for wordlist input and output.
+//!
+//! Wordlists in this corpus (rockyou, SecLists archives) are commonly
+//! redistributed as `.gz`/`.zst`/`.bz2`. Input compression is detected from
+//! the file extension; output compression is whatever `--compress` asked
+//! for, since there's no content to sniff before we've written anything.
+
+use std::fs::File;
+use std::io::{BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+
+/// Compression format, inferred from an input file's extension or requested
+/// for output via `--compress`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compression {
+    /// Plain, uncompressed text
+    None,
+    Gzip,
+    Zstd,
+    /// Input-only: wordlists still show up as `.bz2`, but we never produce it
+    Bzip2,
+}
+
+impl Compression {
+    /// Infer the compression format from a file's extension (`.gz`, `.zst`, `.bz2`).
+    pub fn from_extension(path: &Path) -> Self {
+        match path.extension().and_then(|e| e.to_str()).map(|e| e.to_lowercase()) {
+            Some(ext) if ext == "gz" => Compression::Gzip,
+            Some(ext) if ext == "zst" => Compression::Zstd,
+            Some(ext) if ext == "bz2" => Compression::Bzip2,
+            _ => Compression::None,
+        }
+    }
+
+    /// File extension this compression format adds to output filenames.
+    pub fn extension(&self) -> Option<&'static str> {
+        match self {
+            Compression::None => None,
+            Compression::Gzip => Some("gz"),
+            Compression::Zstd => Some("zst"),
+            Compression::Bzip2 => Some("bz2"),
+        }
+    }
+}
+
+/// Append the compression format's extension to `path`, if it has one.
+pub fn append_extension(path: &Path, compression: Compression) -> PathBuf {
+    match compression.extension() {
+        Some(ext) => {
+            let mut name = path.as_os_str().to_owned();
+            name.push(".");
+            name.push(ext);
+            PathBuf::from(name)
+        }
+        None => path.to_path_buf(),
+    }
+}
+
+/// Open `path` for reading, transparently decompressing if `compression` requires it.
+pub fn open_reader(path: &Path, compression: Compression) -> anyhow::Result<Box<dyn Read>> {
+    let file = File::open(path)?;
+    Ok(match compression {
+        Compression::None => Box::new(file),
+        Compression::Gzip => Box::new(flate2::read::GzDecoder::new(file)),
+        Compression::Zstd => Box::new(zstd::stream::read::Decoder::new(file)?),
+        Compression::Bzip2 => Box::new(bzip2::read::BzDecoder::new(file)),
+    })
+}
+
+/// An output writer that transparently compresses what's written to it.
+///
+/// Each variant's encoder wraps a buffered file writer directly, so
+/// `buffer_size` still controls how much gets batched into a single
+/// underlying `write(2)`. Encoders finish their stream (writing any trailing
+/// checksum/epilogue) automatically on drop.
+pub enum CompressedWriter {
+    Plain(BufWriter<File>),
+    Gzip(Box<flate2::write::GzEncoder<BufWriter<File>>>),
+    // Unlike `GzEncoder`, zstd's raw `Encoder` does *not* write the final
+    // frame on drop -- `auto_finish()` wraps it so dropping this variant
+    // finishes the stream instead of producing a truncated `.zst` file.
+    Zstd(Box<zstd::stream::write::AutoFinishEncoder<'static, BufWriter<File>>>),
+}
+
+impl CompressedWriter {
+    /// Wrap `file` with the encoder matching `compression`. Output never
+    /// uses `Bzip2` since we don't redistribute wordlists in that format.
+    pub fn new(file: File, buffer_size: usize, compression: Compression) -> anyhow::Result<Self> {
+        let buffered = BufWriter::with_capacity(buffer_size, file);
+        Ok(match compression {
+            Compression::None => CompressedWriter::Plain(buffered),
+            Compression::Gzip => CompressedWriter::Gzip(Box::new(flate2::write::GzEncoder::new(
+                buffered,
+                flate2::Compression::default(),
+            ))),
+            Compression::Zstd => CompressedWriter::Zstd(Box::new(
+                zstd::stream::write::Encoder::new(buffered, 0)?.auto_finish(),
+            )),
+            Compression::Bzip2 => anyhow::bail!("bzip2 output is not supported; use gzip or zstd"),
+        })
+    }
+}
+
+impl Write for CompressedWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        match self {
+            CompressedWriter::Plain(w) => w.write(buf),
+            CompressedWriter::Gzip(w) => w.write(buf),
+            CompressedWriter::Zstd(w) => w.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        match self {
+            CompressedWriter::Plain(w) => w.flush(),
+            CompressedWriter::Gzip(w) => w.flush(),
+            CompressedWriter::Zstd(w) => w.flush(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_extension() {
+        assert_eq!(Compression::from_extension(Path::new("rockyou.txt.gz")), Compression::Gzip);
+        assert_eq!(Compression::from_extension(Path::new("rockyou.txt.zst")), Compression::Zstd);
+        assert_eq!(Compression::from_extension(Path::new("rockyou.txt.bz2")), Compression::Bzip2);
+        assert_eq!(Compression::from_extension(Path::new("rockyou.txt")), Compression::None);
+    }
+
+    #[test]
+    fn test_append_extension() {
+        let path = Path::new("/out/filtered.txt");
+        assert_eq!(append_extension(path, Compression::Gzip), PathBuf::from("/out/filtered.txt.gz"));
+        assert_eq!(append_extension(path, Compression::None), PathBuf::from("/out/filtered.txt"));
+    }
+}
@@ -8,8 +8,10 @@
 use ahash::RandomState;
 use hashbrown::HashSet;
 use std::hash::{BuildHasher, Hash, Hasher};
-use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 use std::sync::RwLock;
+use std::time::{Duration, Instant};
+use xxhash_rust::xxh3::{xxh3_128, xxh3_64};
 
 /// Statistics for deduplication operations
 #[derive(Debug, Default)]
@@ -74,57 +76,103 @@ pub trait Deduplicator: Send + Sync {
     fn memory_usage(&self) -> usize;
 }
 
+/// A [`Hasher`] that buffers all written bytes and runs them through xxh3_64
+/// in one shot on `finish`.
+///
+/// Unlike ahash, xxh3 isn't keyed/DoS-resistant, but it's noticeably faster
+/// -- a reasonable trade for trusted local wordlists where nobody is
+/// crafting adversarial inputs to degrade the hash table.
+#[derive(Default)]
+pub struct Xxh3Hasher {
+    buffer: Vec<u8>,
+}
+
+impl Hasher for Xxh3Hasher {
+    fn write(&mut self, bytes: &[u8]) {
+        self.buffer.extend_from_slice(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        xxh3_64(&self.buffer)
+    }
+}
+
+/// [`BuildHasher`] for [`Xxh3Hasher`], selectable via `--hasher xxh3`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Xxh3BuildHasher;
+
+impl BuildHasher for Xxh3BuildHasher {
+    type Hasher = Xxh3Hasher;
+
+    fn build_hasher(&self) -> Xxh3Hasher {
+        Xxh3Hasher::default()
+    }
+}
+
 /// In-memory HashSet-based deduplicator
-/// 
-/// Fastest option but requires enough RAM to hold all unique items.
-pub struct MemoryDeduplicator {
-    set: RwLock<HashSet<String, RandomState>>,
-    hasher: RandomState,
+///
+/// Fastest option but requires enough RAM to hold all unique items. Generic
+/// over the hashing algorithm (defaults to ahash, as before); see
+/// [`Xxh3BuildHasher`] for a faster, non-DoS-resistant alternative.
+pub struct MemoryDeduplicator<S = RandomState> {
+    set: RwLock<HashSet<String, S>>,
 }
 
-impl MemoryDeduplicator {
+impl MemoryDeduplicator<RandomState> {
     pub fn new() -> Self {
         Self {
             set: RwLock::new(HashSet::with_hasher(RandomState::new())),
-            hasher: RandomState::new(),
         }
     }
-    
+
     pub fn with_capacity(capacity: usize) -> Self {
         Self {
             set: RwLock::new(HashSet::with_capacity_and_hasher(capacity, RandomState::new())),
-            hasher: RandomState::new(),
         }
     }
 }
 
-impl Default for MemoryDeduplicator {
+impl<S: BuildHasher + Default> MemoryDeduplicator<S> {
+    pub fn with_hasher() -> Self {
+        Self {
+            set: RwLock::new(HashSet::with_hasher(S::default())),
+        }
+    }
+
+    pub fn with_capacity_and_hasher(capacity: usize) -> Self {
+        Self {
+            set: RwLock::new(HashSet::with_capacity_and_hasher(capacity, S::default())),
+        }
+    }
+}
+
+impl Default for MemoryDeduplicator<RandomState> {
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl Deduplicator for MemoryDeduplicator {
+impl<S: BuildHasher + Send + Sync> Deduplicator for MemoryDeduplicator<S> {
     fn insert(&self, item: &str) -> bool {
         let mut set = self.set.write().unwrap();
         set.insert(item.to_string())
     }
-    
+
     fn contains(&self, item: &str) -> bool {
         let set = self.set.read().unwrap();
         set.contains(item)
     }
-    
+
     fn len(&self) -> usize {
         let set = self.set.read().unwrap();
         set.len()
     }
-    
+
     fn clear(&self) {
         let mut set = self.set.write().unwrap();
         set.clear();
     }
-    
+
     fn memory_usage(&self) -> usize {
         let set = self.set.read().unwrap();
         // Approximate: each entry is roughly String overhead + content + HashSet overhead
@@ -136,77 +184,96 @@ impl Deduplicator for MemoryDeduplicator {
 ///
 /// Uses much less memory than HashSet but has a small false positive rate.
 /// False positives mean some unique items might be incorrectly marked as duplicates.
-pub struct BloomDeduplicator {
+///
+/// Generic over the hashing algorithm used to derive its two hash
+/// functions from two separate hasher states, `hasher1` and `hasher2`.
+/// `hasher2` is additionally salted with `h1`, so a stateless hasher (whose
+/// two states are identical) still yields distinct `h1`/`h2`.
+pub struct BloomDeduplicator<S = RandomState> {
     bits: Vec<AtomicU64>,
     num_hashes: usize,
-    hasher: RandomState,
+    hasher1: S,
+    hasher2: S,
     estimated_count: AtomicU64,
 }
 
-impl BloomDeduplicator {
+impl BloomDeduplicator<RandomState> {
     /// Create a new bloom filter
-    /// 
+    ///
     /// # Arguments
     /// * `expected_items` - Expected number of unique items
     /// * `false_positive_rate` - Desired false positive rate (e.g., 0.001 for 0.1%)
     pub fn new(expected_items: usize, false_positive_rate: f64) -> Self {
+        Self::with_hasher(expected_items, false_positive_rate, RandomState::new(), RandomState::new())
+    }
+
+    /// Create with specific parameters
+    pub fn with_params(num_bits: usize, num_hashes: usize) -> Self {
+        let num_u64s = (num_bits + 63) / 64;
+        let bits = (0..num_u64s).map(|_| AtomicU64::new(0)).collect();
+
+        Self {
+            bits,
+            num_hashes,
+            hasher1: RandomState::new(),
+            hasher2: RandomState::new(),
+            estimated_count: AtomicU64::new(0),
+        }
+    }
+}
+
+impl<S: BuildHasher> BloomDeduplicator<S> {
+    /// Create a bloom filter seeded from two independent hasher states.
+    pub fn with_hasher(expected_items: usize, false_positive_rate: f64, hasher1: S, hasher2: S) -> Self {
         // Calculate optimal size and number of hash functions
         let ln2 = std::f64::consts::LN_2;
         let ln2_squared = ln2 * ln2;
-        
+
         // m = -n * ln(p) / (ln(2)^2)
         let num_bits = (-(expected_items as f64) * false_positive_rate.ln() / ln2_squared).ceil() as usize;
         let num_bits = num_bits.max(64); // Minimum 64 bits
-        
+
         // k = (m/n) * ln(2)
         let num_hashes = ((num_bits as f64 / expected_items as f64) * ln2).ceil() as usize;
         let num_hashes = num_hashes.clamp(1, 16); // Between 1 and 16 hash functions
-        
+
         // Round up to multiple of 64 for AtomicU64
         let num_u64s = (num_bits + 63) / 64;
-        
-        let bits = (0..num_u64s).map(|_| AtomicU64::new(0)).collect();
-        
-        Self {
-            bits,
-            num_hashes,
-            hasher: RandomState::new(),
-            estimated_count: AtomicU64::new(0),
-        }
-    }
-    
-    /// Create with specific parameters
-    pub fn with_params(num_bits: usize, num_hashes: usize) -> Self {
-        let num_u64s = (num_bits + 63) / 64;
+
         let bits = (0..num_u64s).map(|_| AtomicU64::new(0)).collect();
-        
+
         Self {
             bits,
             num_hashes,
-            hasher: RandomState::new(),
+            hasher1,
+            hasher2,
             estimated_count: AtomicU64::new(0),
         }
     }
-    
+
     fn get_hash_indices(&self, item: &str) -> Vec<usize> {
         let num_bits = self.bits.len() * 64;
         let mut indices = Vec::with_capacity(self.num_hashes);
-        
-        // Use double hashing technique
-        let mut hasher1 = self.hasher.build_hasher();
+
+        // Double hashing, with h1/h2 from two independently-seeded states.
+        // `hasher1`/`hasher2` are only actually independent for `RandomState`;
+        // a stateless hasher like `Xxh3BuildHasher` would otherwise produce
+        // identical h1/h2 for both fields, so salt hasher2 with h1 first
+        // (same trick as `RotatingBloomDeduplicator::hash_indices`).
+        let mut hasher1 = self.hasher1.build_hasher();
         item.hash(&mut hasher1);
         let h1 = hasher1.finish() as usize;
-        
-        let mut hasher2 = self.hasher.build_hasher();
+
+        let mut hasher2 = self.hasher2.build_hasher();
         hasher2.write_usize(h1);
         item.hash(&mut hasher2);
         let h2 = hasher2.finish() as usize;
-        
+
         for i in 0..self.num_hashes {
             let index = (h1.wrapping_add(i.wrapping_mul(h2))) % num_bits;
             indices.push(index);
         }
-        
+
         indices
     }
     
@@ -228,7 +295,7 @@ impl BloomDeduplicator {
     }
 }
 
-impl Deduplicator for BloomDeduplicator {
+impl<S: BuildHasher + Send + Sync> Deduplicator for BloomDeduplicator<S> {
     fn insert(&self, item: &str) -> bool {
         let indices = self.get_hash_indices(item);
         
@@ -275,35 +342,269 @@ impl Deduplicator for BloomDeduplicator {
     }
 }
 
-/// Sharded memory deduplicator for better parallel performance
-pub struct ShardedDeduplicator {
-    shards: Vec<RwLock<HashSet<String, RandomState>>>,
+/// One generation of a [`RotatingBloomDeduplicator`]'s bit array.
+///
+/// `popcount` tracks exactly the number of bits that have transitioned
+/// 0→1 in this generation, which is all the false-positive estimate
+/// needs: `(popcount / num_bits) ^ num_hashes`.
+struct BloomGeneration {
+    bits: Vec<AtomicU64>,
+    popcount: AtomicU64,
+}
+
+impl BloomGeneration {
+    fn new(num_u64s: usize) -> Self {
+        Self {
+            bits: (0..num_u64s).map(|_| AtomicU64::new(0)).collect(),
+            popcount: AtomicU64::new(0),
+        }
+    }
+
+    /// Set a bit, returning true if it transitioned 0→1.
+    fn set_bit(&self, index: usize) -> bool {
+        let u64_index = index / 64;
+        let bit_index = index % 64;
+        let mask = 1u64 << bit_index;
+
+        let old = self.bits[u64_index].fetch_or(mask, Ordering::Relaxed);
+        let was_unset = (old & mask) == 0;
+        if was_unset {
+            self.popcount.fetch_add(1, Ordering::Relaxed);
+        }
+        was_unset
+    }
+
+    fn get_bit(&self, index: usize) -> bool {
+        let u64_index = index / 64;
+        let bit_index = index % 64;
+        let mask = 1u64 << bit_index;
+
+        (self.bits[u64_index].load(Ordering::Relaxed) & mask) != 0
+    }
+
+    fn clear(&self) {
+        for word in &self.bits {
+            word.store(0, Ordering::Relaxed);
+        }
+        self.popcount.store(0, Ordering::Relaxed);
+    }
+}
+
+/// Self-tuning bloom filter that bounds its own false-positive rate on
+/// long-running/streaming passes.
+///
+/// [`BloomDeduplicator`] never resets, so its effective false-positive rate
+/// climbs without bound as it saturates. This variant keeps two generations
+/// of bit arrays (active + previous): `insert`/`contains` check both, but
+/// new bits are only ever set in the active one. Once the active
+/// generation's estimated false-positive rate -- `(s / m)^k`, from its
+/// popcount `s` over `m` total bits, no re-scan needed -- exceeds
+/// `max_fp_rate` (or an optional item-count/age bound is hit), the previous
+/// generation is cleared and promoted to active. Recent history survives
+/// the rotation because the just-demoted generation still answers
+/// `contains`/`insert` checks until *it* gets cleared on the next rotation.
+pub struct RotatingBloomDeduplicator {
+    generations: [BloomGeneration; 2],
+    active: AtomicUsize,
+    num_bits: usize,
+    num_hashes: usize,
     hasher: RandomState,
+    max_fp_rate: f64,
+    max_items: Option<u64>,
+    max_age: Option<Duration>,
+    items_since_rotation: AtomicU64,
+    rotation_started: RwLock<Instant>,
+    unique_count: AtomicU64,
 }
 
-impl ShardedDeduplicator {
-    pub fn new(num_shards: usize) -> Self {
-        let shards = (0..num_shards)
-            .map(|_| RwLock::new(HashSet::with_hasher(RandomState::new())))
-            .collect();
-        
+impl RotatingBloomDeduplicator {
+    /// Create a new rotating bloom filter.
+    ///
+    /// `false_positive_rate` is both the target used to size the bit array
+    /// (as in [`BloomDeduplicator::new`]) and the ceiling a generation is
+    /// allowed to reach before it's rotated out.
+    pub fn new(expected_items: usize, false_positive_rate: f64) -> Self {
+        let ln2 = std::f64::consts::LN_2;
+        let ln2_squared = ln2 * ln2;
+
+        let num_bits = (-(expected_items as f64) * false_positive_rate.ln() / ln2_squared).ceil() as usize;
+        let num_bits = num_bits.max(64);
+
+        let num_hashes = ((num_bits as f64 / expected_items as f64) * ln2).ceil() as usize;
+        let num_hashes = num_hashes.clamp(1, 16);
+
+        let num_u64s = (num_bits + 63) / 64;
+
         Self {
-            shards,
+            generations: [BloomGeneration::new(num_u64s), BloomGeneration::new(num_u64s)],
+            active: AtomicUsize::new(0),
+            num_bits: num_u64s * 64,
+            num_hashes,
             hasher: RandomState::new(),
+            max_fp_rate: false_positive_rate,
+            max_items: None,
+            max_age: None,
+            items_since_rotation: AtomicU64::new(0),
+            rotation_started: RwLock::new(Instant::now()),
+            unique_count: AtomicU64::new(0),
         }
     }
-    
+
+    /// Also rotate once `max_items` have been inserted since the last rotation.
+    pub fn with_max_items(mut self, max_items: u64) -> Self {
+        self.max_items = Some(max_items);
+        self
+    }
+
+    /// Also rotate once `max_age` has elapsed since the last rotation.
+    pub fn with_max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = Some(max_age);
+        self
+    }
+
+    fn hash_indices(&self, item: &str) -> Vec<usize> {
+        // Use double hashing technique, same as `BloomDeduplicator`.
+        let mut hasher1 = self.hasher.build_hasher();
+        item.hash(&mut hasher1);
+        let h1 = hasher1.finish() as usize;
+
+        let mut hasher2 = self.hasher.build_hasher();
+        hasher2.write_usize(h1);
+        item.hash(&mut hasher2);
+        let h2 = hasher2.finish() as usize;
+
+        (0..self.num_hashes)
+            .map(|i| (h1.wrapping_add(i.wrapping_mul(h2))) % self.num_bits)
+            .collect()
+    }
+
+    fn active_index(&self) -> usize {
+        self.active.load(Ordering::Relaxed)
+    }
+
+    /// Estimated false-positive probability of the active generation,
+    /// computed from its bit saturation without re-scanning the array.
+    pub fn current_fp_rate(&self) -> f64 {
+        let active = &self.generations[self.active_index()];
+        let s = active.popcount.load(Ordering::Relaxed) as f64;
+        let m = self.num_bits as f64;
+        (s / m).powi(self.num_hashes as i32)
+    }
+
+    /// Rotate if the active generation has exceeded any configured bound.
+    fn maybe_rotate(&self) {
+        let over_fp_rate = self.current_fp_rate() >= self.max_fp_rate;
+        let over_item_count = self
+            .max_items
+            .is_some_and(|max| self.items_since_rotation.load(Ordering::Relaxed) >= max);
+        let over_age = self
+            .max_age
+            .is_some_and(|max| self.rotation_started.read().unwrap().elapsed() >= max);
+
+        if over_fp_rate || over_item_count || over_age {
+            self.rotate();
+        }
+    }
+
+    /// Clear the previous generation and promote it to active; the current
+    /// active generation becomes the new previous, still answering lookups
+    /// until it's cleared on the next rotation.
+    fn rotate(&self) {
+        let new_active = 1 - self.active_index();
+        self.generations[new_active].clear();
+        self.active.store(new_active, Ordering::Relaxed);
+        self.items_since_rotation.store(0, Ordering::Relaxed);
+        *self.rotation_started.write().unwrap() = Instant::now();
+    }
+}
+
+impl Deduplicator for RotatingBloomDeduplicator {
+    fn insert(&self, item: &str) -> bool {
+        let indices = self.hash_indices(item);
+        self.items_since_rotation.fetch_add(1, Ordering::Relaxed);
+
+        let active_idx = self.active_index();
+        let previous_idx = 1 - active_idx;
+        let active = &self.generations[active_idx];
+        let previous = &self.generations[previous_idx];
+
+        let probably_exists = indices.iter().all(|&i| active.get_bit(i) || previous.get_bit(i));
+
+        if !probably_exists {
+            for &index in &indices {
+                active.set_bit(index);
+            }
+            self.unique_count.fetch_add(1, Ordering::Relaxed);
+        }
+
+        self.maybe_rotate();
+
+        !probably_exists
+    }
+
+    fn contains(&self, item: &str) -> bool {
+        let indices = self.hash_indices(item);
+        let active_idx = self.active_index();
+        let previous_idx = 1 - active_idx;
+        let active = &self.generations[active_idx];
+        let previous = &self.generations[previous_idx];
+
+        indices.iter().all(|&i| active.get_bit(i) || previous.get_bit(i))
+    }
+
+    fn len(&self) -> usize {
+        self.unique_count.load(Ordering::Relaxed) as usize
+    }
+
+    fn clear(&self) {
+        for generation in &self.generations {
+            generation.clear();
+        }
+        self.active.store(0, Ordering::Relaxed);
+        self.items_since_rotation.store(0, Ordering::Relaxed);
+        *self.rotation_started.write().unwrap() = Instant::now();
+        self.unique_count.store(0, Ordering::Relaxed);
+    }
+
+    fn memory_usage(&self) -> usize {
+        self.generations.iter().map(|g| g.bits.len() * 8).sum()
+    }
+}
+
+/// Sharded memory deduplicator for better parallel performance
+pub struct ShardedDeduplicator<S = RandomState> {
+    shards: Vec<RwLock<HashSet<String, S>>>,
+    hasher: S,
+}
+
+impl ShardedDeduplicator<RandomState> {
+    pub fn new(num_shards: usize) -> Self {
+        Self::with_hasher(num_shards, RandomState::new())
+    }
+
     pub fn with_capacity(num_shards: usize, capacity_per_shard: usize) -> Self {
+        Self::with_capacity_and_hasher(num_shards, capacity_per_shard, RandomState::new())
+    }
+}
+
+impl<S: BuildHasher + Clone> ShardedDeduplicator<S> {
+    /// Create with every shard (and shard routing) seeded from `hasher`.
+    pub fn with_hasher(num_shards: usize, hasher: S) -> Self {
         let shards = (0..num_shards)
-            .map(|_| RwLock::new(HashSet::with_capacity_and_hasher(capacity_per_shard, RandomState::new())))
+            .map(|_| RwLock::new(HashSet::with_hasher(hasher.clone())))
             .collect();
-        
-        Self {
-            shards,
-            hasher: RandomState::new(),
-        }
+
+        Self { shards, hasher }
     }
-    
+
+    pub fn with_capacity_and_hasher(num_shards: usize, capacity_per_shard: usize, hasher: S) -> Self {
+        let shards = (0..num_shards)
+            .map(|_| RwLock::new(HashSet::with_capacity_and_hasher(capacity_per_shard, hasher.clone())))
+            .collect();
+
+        Self { shards, hasher }
+    }
+
     fn get_shard_index(&self, item: &str) -> usize {
         let mut hasher = self.hasher.build_hasher();
         item.hash(&mut hasher);
@@ -311,7 +612,7 @@ impl ShardedDeduplicator {
     }
 }
 
-impl Deduplicator for ShardedDeduplicator {
+impl<S: BuildHasher + Send + Sync> Deduplicator for ShardedDeduplicator<S> {
     fn insert(&self, item: &str) -> bool {
         let shard_idx = self.get_shard_index(item);
         let mut shard = self.shards[shard_idx].write().unwrap();
@@ -346,39 +647,178 @@ impl Deduplicator for ShardedDeduplicator {
     }
 }
 
-/// No-op deduplicator for when deduplication is disabled
-pub struct NoOpDeduplicator {
-    count: AtomicU64,
+/// Memory-bounded deduplicator that stores a 64-bit xxh3 fingerprint of each
+/// trimmed line instead of the line itself.
+///
+/// This drops per-unique memory from ~(len + overhead) bytes down to 8 bytes,
+/// at the cost of a birthday-bound collision risk: two distinct words that
+/// hash to the same 64-bit digest will silently collapse into one entry.
+/// That becomes non-negligible around ~4 billion lines, so this mode is opt-in
+/// via `--dedup-mode hash64`; the exact string-based deduplicators remain the
+/// default.
+pub struct Hash64Deduplicator {
+    shards: Vec<RwLock<HashSet<u64, RandomState>>>,
 }
 
-impl NoOpDeduplicator {
-    pub fn new() -> Self {
-        Self {
-            count: AtomicU64::new(0),
-        }
+impl Hash64Deduplicator {
+    pub fn new(num_shards: usize) -> Self {
+        let shards = (0..num_shards)
+            .map(|_| RwLock::new(HashSet::with_hasher(RandomState::new())))
+            .collect();
+
+        Self { shards }
     }
-}
 
-impl Default for NoOpDeduplicator {
-    fn default() -> Self {
-        Self::new()
+    pub fn with_capacity(num_shards: usize, capacity_per_shard: usize) -> Self {
+        let shards = (0..num_shards)
+            .map(|_| RwLock::new(HashSet::with_capacity_and_hasher(capacity_per_shard, RandomState::new())))
+            .collect();
+
+        Self { shards }
+    }
+
+    fn shard_for(&self, digest: u64) -> usize {
+        // Shard by the high bits of the digest so shard selection is
+        // independent of the low bits used within the HashSet itself.
+        ((digest >> 48) as usize) % self.shards.len()
     }
 }
 
-impl Deduplicator for NoOpDeduplicator {
-    fn insert(&self, _item: &str) -> bool {
-        self.count.fetch_add(1, Ordering::Relaxed);
-        true // Always "unique" since we don't track
+impl Deduplicator for Hash64Deduplicator {
+    fn insert(&self, item: &str) -> bool {
+        let digest = xxh3_64(item.as_bytes());
+        let mut shard = self.shards[self.shard_for(digest)].write().unwrap();
+        shard.insert(digest)
     }
-    
-    fn contains(&self, _item: &str) -> bool {
-        false // Never contains anything
+
+    fn contains(&self, item: &str) -> bool {
+        let digest = xxh3_64(item.as_bytes());
+        let shard = self.shards[self.shard_for(digest)].read().unwrap();
+        shard.contains(&digest)
     }
-    
+
     fn len(&self) -> usize {
-        self.count.load(Ordering::Relaxed) as usize
+        self.shards.iter()
+            .map(|s| s.read().unwrap().len())
+            .sum()
     }
-    
+
+    fn clear(&self) {
+        for shard in &self.shards {
+            shard.write().unwrap().clear();
+        }
+    }
+
+    fn memory_usage(&self) -> usize {
+        self.shards.iter()
+            .map(|s| {
+                let set = s.read().unwrap();
+                set.capacity() * std::mem::size_of::<u64>()
+            })
+            .sum()
+    }
+}
+
+/// Like [`Hash64Deduplicator`] but fingerprints with the 128-bit xxh3 variant.
+///
+/// At 16 bytes per unique item this is only marginally larger than the 64-bit
+/// mode, but pushes the collision bound out far enough to be negligible for
+/// any wordlist size in practice, making it the recommended hash mode for
+/// huge inputs.
+pub struct Hash128Deduplicator {
+    shards: Vec<RwLock<HashSet<u128, RandomState>>>,
+}
+
+impl Hash128Deduplicator {
+    pub fn new(num_shards: usize) -> Self {
+        let shards = (0..num_shards)
+            .map(|_| RwLock::new(HashSet::with_hasher(RandomState::new())))
+            .collect();
+
+        Self { shards }
+    }
+
+    pub fn with_capacity(num_shards: usize, capacity_per_shard: usize) -> Self {
+        let shards = (0..num_shards)
+            .map(|_| RwLock::new(HashSet::with_capacity_and_hasher(capacity_per_shard, RandomState::new())))
+            .collect();
+
+        Self { shards }
+    }
+
+    fn shard_for(&self, digest: u128) -> usize {
+        ((digest >> 112) as usize) % self.shards.len()
+    }
+}
+
+impl Deduplicator for Hash128Deduplicator {
+    fn insert(&self, item: &str) -> bool {
+        let digest = xxh3_128(item.as_bytes());
+        let mut shard = self.shards[self.shard_for(digest)].write().unwrap();
+        shard.insert(digest)
+    }
+
+    fn contains(&self, item: &str) -> bool {
+        let digest = xxh3_128(item.as_bytes());
+        let shard = self.shards[self.shard_for(digest)].read().unwrap();
+        shard.contains(&digest)
+    }
+
+    fn len(&self) -> usize {
+        self.shards.iter()
+            .map(|s| s.read().unwrap().len())
+            .sum()
+    }
+
+    fn clear(&self) {
+        for shard in &self.shards {
+            shard.write().unwrap().clear();
+        }
+    }
+
+    fn memory_usage(&self) -> usize {
+        self.shards.iter()
+            .map(|s| {
+                let set = s.read().unwrap();
+                set.capacity() * std::mem::size_of::<u128>()
+            })
+            .sum()
+    }
+}
+
+/// No-op deduplicator for when deduplication is disabled
+pub struct NoOpDeduplicator {
+    count: AtomicU64,
+}
+
+impl NoOpDeduplicator {
+    pub fn new() -> Self {
+        Self {
+            count: AtomicU64::new(0),
+        }
+    }
+}
+
+impl Default for NoOpDeduplicator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Deduplicator for NoOpDeduplicator {
+    fn insert(&self, _item: &str) -> bool {
+        self.count.fetch_add(1, Ordering::Relaxed);
+        true // Always "unique" since we don't track
+    }
+    
+    fn contains(&self, _item: &str) -> bool {
+        false // Never contains anything
+    }
+    
+    fn len(&self) -> usize {
+        self.count.load(Ordering::Relaxed) as usize
+    }
+    
     fn clear(&self) {
         self.count.store(0, Ordering::Relaxed);
     }
@@ -388,29 +828,408 @@ impl Deduplicator for NoOpDeduplicator {
     }
 }
 
+/// Blanket impl so a boxed dedup can itself be wrapped -- e.g. by
+/// [`NormalizingDeduplicator`] -- without callers needing to unbox it first.
+impl Deduplicator for Box<dyn Deduplicator> {
+    fn insert(&self, item: &str) -> bool {
+        (**self).insert(item)
+    }
+
+    fn contains(&self, item: &str) -> bool {
+        (**self).contains(item)
+    }
+
+    fn len(&self) -> usize {
+        (**self).len()
+    }
+
+    fn clear(&self) {
+        (**self).clear()
+    }
+
+    fn memory_usage(&self) -> usize {
+        (**self).memory_usage()
+    }
+}
+
+/// Computes a canonical dedup key for a word.
+///
+/// [`NormalizingDeduplicator`] feeds the *key* to the wrapped deduplicator
+/// while the caller still writes out the original word, so near-duplicates
+/// like `P@ssw0rd` and `Password` collapse to one entry without losing the
+/// first-seen spelling.
+pub trait KeyNormalizer: Send + Sync {
+    fn normalize(&self, word: &str) -> String;
+}
+
+/// Case-folds the word; `Password` and `password` become the same key.
+pub struct CaseFoldNormalizer;
+
+impl KeyNormalizer for CaseFoldNormalizer {
+    fn normalize(&self, word: &str) -> String {
+        word.to_lowercase()
+    }
+}
+
+/// Case-folds and collapses common leet-speak substitutions (`@`/`4`->a,
+/// `3`->e, `0`->o, `1`->i, `$`->s), so `P@ssw0rd`, `passw0rd`, and `Password`
+/// all normalize to `password`.
+pub struct LeetSpeakNormalizer;
+
+impl KeyNormalizer for LeetSpeakNormalizer {
+    fn normalize(&self, word: &str) -> String {
+        word.chars()
+            .map(|c| match c.to_ascii_lowercase() {
+                '@' => 'a',
+                '4' => 'a',
+                '3' => 'e',
+                '0' => 'o',
+                '1' => 'i',
+                '$' => 's',
+                other => other,
+            })
+            .collect::<String>()
+            .to_lowercase()
+    }
+}
+
+/// Which Unicode normal form [`UnicodeNormalizer`] folds a word to before
+/// case-folding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnicodeForm {
+    /// Canonical composition
+    Nfc,
+    /// Compatibility composition (also folds visually-equivalent code points,
+    /// e.g. full-width Latin letters onto their ASCII equivalents)
+    Nfkc,
+}
+
+/// Unicode-normalizes the word (NFC or NFKC), then case-folds it, so
+/// visually identical strings built from different code point sequences
+/// collapse onto the same key.
+pub struct UnicodeNormalizer {
+    form: UnicodeForm,
+}
+
+impl UnicodeNormalizer {
+    pub fn new(form: UnicodeForm) -> Self {
+        Self { form }
+    }
+}
+
+impl KeyNormalizer for UnicodeNormalizer {
+    fn normalize(&self, word: &str) -> String {
+        use unicode_normalization::UnicodeNormalization;
+
+        let normalized: String = match self.form {
+            UnicodeForm::Nfc => word.nfc().collect(),
+            UnicodeForm::Nfkc => word.nfkc().collect(),
+        };
+        normalized.to_lowercase()
+    }
+}
+
+/// Wraps any [`Deduplicator`] so it dedups on a [`KeyNormalizer`]'s
+/// canonical key instead of the exact string. The wrapped deduplicator never
+/// sees the original word, only the key -- the caller is still responsible
+/// for writing out the original on a unique `insert`, exactly as it does for
+/// any other `Deduplicator`.
+pub struct NormalizingDeduplicator<D: Deduplicator> {
+    inner: D,
+    normalizer: Box<dyn KeyNormalizer>,
+}
+
+impl<D: Deduplicator> NormalizingDeduplicator<D> {
+    pub fn new(inner: D, normalizer: Box<dyn KeyNormalizer>) -> Self {
+        Self { inner, normalizer }
+    }
+}
+
+impl<D: Deduplicator> Deduplicator for NormalizingDeduplicator<D> {
+    fn insert(&self, item: &str) -> bool {
+        let key = self.normalizer.normalize(item);
+        self.inner.insert(&key)
+    }
+
+    fn contains(&self, item: &str) -> bool {
+        let key = self.normalizer.normalize(item);
+        self.inner.contains(&key)
+    }
+
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    fn clear(&self) {
+        self.inner.clear()
+    }
+
+    fn memory_usage(&self) -> usize {
+        self.inner.memory_usage()
+    }
+}
+
+/// Disk-backed deduplicator (the `disk-dedup` feature), for dedup sets too
+/// large to fit in RAM even as hash fingerprints.
+///
+/// Backed by an embedded RocksDB store at `db_path`, so a run can be resumed
+/// against the same path later and pick up where it left off. Two things
+/// keep it from being disk-latency-bound on the common case:
+/// - An in-memory [`BloomDeduplicator`] fronts every lookup. A bloom miss is
+///   a guaranteed true negative, so an `insert` of a never-seen item never
+///   touches the database; only bloom hits fall through to the precise
+///   buffer/database check.
+/// - New items are buffered in a small in-memory `HashSet` and only flushed
+///   to RocksDB as a single `WriteBatch` once the buffer reaches
+///   `batch_size`, trading a little durability lag for write throughput.
+///
+/// `len` is tracked by an atomic counter that's persisted alongside the data
+/// under [`DiskDeduplicator::COUNT_KEY`] so it survives across invocations.
+#[cfg(feature = "disk-dedup")]
+pub struct DiskDeduplicator {
+    db: rocksdb::DB,
+    db_path: std::path::PathBuf,
+    bloom: BloomDeduplicator,
+    pending: std::sync::Mutex<HashSet<String>>,
+    batch_size: usize,
+    count: AtomicU64,
+}
+
+#[cfg(feature = "disk-dedup")]
+impl DiskDeduplicator {
+    /// RocksDB key the running unique-item count is persisted under, so it
+    /// survives across resumed runs against the same `db_path`.
+    const COUNT_KEY: &'static [u8] = b"__wordlist_filter_dedup_count";
+
+    /// Open (or create) a disk-backed deduplicator at `db_path`, sized for
+    /// roughly `expected_items` unique entries. Buffers up to 10,000 inserts
+    /// before flushing a `WriteBatch`; see [`Self::with_batch_size`] to tune it.
+    pub fn new(db_path: impl Into<std::path::PathBuf>, expected_items: usize) -> anyhow::Result<Self> {
+        Self::with_batch_size(db_path, expected_items, 10_000)
+    }
+
+    /// Same as [`Self::new`], with an explicit write-back batch size.
+    pub fn with_batch_size(
+        db_path: impl Into<std::path::PathBuf>,
+        expected_items: usize,
+        batch_size: usize,
+    ) -> anyhow::Result<Self> {
+        let db_path = db_path.into();
+
+        let mut opts = rocksdb::Options::default();
+        opts.create_if_missing(true);
+        let db = rocksdb::DB::open(&opts, &db_path)?;
+
+        let count = db
+            .get(Self::COUNT_KEY)?
+            .and_then(|bytes| bytes.as_slice().try_into().ok())
+            .map(u64::from_le_bytes)
+            .unwrap_or(0);
+
+        Ok(Self {
+            db,
+            db_path,
+            bloom: BloomDeduplicator::new(expected_items.max(1), 0.001),
+            pending: std::sync::Mutex::new(HashSet::new()),
+            batch_size,
+            count: AtomicU64::new(count),
+        })
+    }
+
+    /// The on-disk database path, so a caller can resume against the same
+    /// store on a later invocation.
+    pub fn db_path(&self) -> &std::path::Path {
+        &self.db_path
+    }
+
+    /// Whether `item` is present in the write-back buffer or on disk. Only
+    /// reached once the bloom filter can't rule it out on its own.
+    fn contains_buffered_or_disk(&self, item: &str) -> bool {
+        if self.pending.lock().unwrap().contains(item) {
+            return true;
+        }
+        matches!(self.db.get(item.as_bytes()), Ok(Some(_)))
+    }
+
+    /// Flush the pending write-back buffer to RocksDB as a single batch,
+    /// persisting the running count alongside it.
+    fn flush(&self, pending: &mut HashSet<String>) {
+        if pending.is_empty() {
+            return;
+        }
+
+        let mut batch = rocksdb::WriteBatch::default();
+        for word in pending.drain() {
+            batch.put(word.as_bytes(), b"1");
+        }
+        batch.put(Self::COUNT_KEY, self.count.load(Ordering::Relaxed).to_le_bytes());
+        let _ = self.db.write(batch);
+    }
+}
+
+#[cfg(feature = "disk-dedup")]
+impl Deduplicator for DiskDeduplicator {
+    fn insert(&self, item: &str) -> bool {
+        // A bloom miss (insert returns true) is a guaranteed true negative,
+        // so it can skip straight to buffering. A bloom hit might just be a
+        // false positive, so it still needs the precise check below.
+        if !self.bloom.insert(item) && self.contains_buffered_or_disk(item) {
+            return false;
+        }
+
+        let mut pending = self.pending.lock().unwrap();
+        if !pending.insert(item.to_string()) {
+            return false;
+        }
+        self.count.fetch_add(1, Ordering::Relaxed);
+        if pending.len() >= self.batch_size {
+            self.flush(&mut pending);
+        }
+        true
+    }
+
+    fn contains(&self, item: &str) -> bool {
+        self.bloom.contains(item) && self.contains_buffered_or_disk(item)
+    }
+
+    fn len(&self) -> usize {
+        self.count.load(Ordering::Relaxed) as usize
+    }
+
+    fn clear(&self) {
+        let mut pending = self.pending.lock().unwrap();
+        pending.clear();
+        self.bloom.clear();
+        self.count.store(0, Ordering::Relaxed);
+
+        for key in self.db.iterator(rocksdb::IteratorMode::Start).flatten() {
+            let _ = self.db.delete(key.0);
+        }
+    }
+
+    fn memory_usage(&self) -> usize {
+        let pending = self.pending.lock().unwrap();
+        self.bloom.memory_usage() + pending.iter().map(|w| w.len()).sum::<usize>()
+    }
+}
+
+/// Flushes whatever is still in the write-back buffer (and the running
+/// count) on drop, so a later run against the same `db_path` resumes it.
+#[cfg(feature = "disk-dedup")]
+impl Drop for DiskDeduplicator {
+    fn drop(&mut self) {
+        if let Ok(mut pending) = self.pending.lock() {
+            self.flush(&mut pending);
+        }
+    }
+}
+
 /// Factory for creating deduplicators based on configuration
 pub fn create_deduplicator(
     strategy: crate::cli::DedupStrategy,
     expected_items: usize,
     memory_limit: usize,
-) -> Box<dyn Deduplicator> {
-    match strategy {
+) -> anyhow::Result<Box<dyn Deduplicator>> {
+    create_deduplicator_with_mode(strategy, crate::cli::DedupMode::Exact, expected_items, memory_limit)
+}
+
+/// Factory for creating deduplicators based on configuration, additionally
+/// selecting between exact string storage and a hash-fingerprint mode.
+///
+/// `dedup_mode` only affects the [`DedupStrategy::Memory`] family: bloom and
+/// disk-based strategies already operate on hashes or external storage and
+/// ignore it. Uses ahash; see [`create_deduplicator_with_hasher`] to pick
+/// xxh3 instead.
+pub fn create_deduplicator_with_mode(
+    strategy: crate::cli::DedupStrategy,
+    dedup_mode: crate::cli::DedupMode,
+    expected_items: usize,
+    memory_limit: usize,
+) -> anyhow::Result<Box<dyn Deduplicator>> {
+    create_deduplicator_with_hasher(strategy, dedup_mode, crate::cli::HasherAlgo::Ahash, expected_items, memory_limit)
+}
+
+/// Factory for creating deduplicators, additionally selecting the hashing
+/// algorithm used by the [`DedupStrategy::Memory`] and [`DedupStrategy::Bloom`]
+/// families (ahash, the default, or xxh3 for raw speed on trusted input).
+/// `Hash64`/`Hash128` dedup modes already fingerprint with xxh3 directly,
+/// and `RotatingBloom` always hashes with ahash; all of them ignore
+/// `hasher_algo`.
+///
+/// [`DedupStrategy::Disk`] always opens its database at the OS-temp-dir
+/// default path; use [`create_deduplicator_with_disk_path`] to pick a
+/// specific path for resumable runs. Returns an error rather than panicking
+/// when that database fails to open (e.g. another run already holds its
+/// RocksDB lock).
+pub fn create_deduplicator_with_hasher(
+    strategy: crate::cli::DedupStrategy,
+    dedup_mode: crate::cli::DedupMode,
+    hasher_algo: crate::cli::HasherAlgo,
+    expected_items: usize,
+    memory_limit: usize,
+) -> anyhow::Result<Box<dyn Deduplicator>> {
+    create_deduplicator_with_disk_path(strategy, dedup_mode, hasher_algo, expected_items, memory_limit, None)
+}
+
+/// Same as [`create_deduplicator_with_hasher`], additionally taking the
+/// on-disk dedup database path used by [`DedupStrategy::Disk`] (the
+/// `--disk-dedup-path` flag), so a caller can resume a previous run's
+/// dedup state instead of always starting a fresh temp-dir database.
+/// Ignored by every other strategy.
+pub fn create_deduplicator_with_disk_path(
+    strategy: crate::cli::DedupStrategy,
+    dedup_mode: crate::cli::DedupMode,
+    hasher_algo: crate::cli::HasherAlgo,
+    expected_items: usize,
+    _memory_limit: usize,
+    #[cfg_attr(not(feature = "disk-dedup"), allow(unused_variables))] disk_dedup_path: Option<std::path::PathBuf>,
+) -> anyhow::Result<Box<dyn Deduplicator>> {
+    Ok(match strategy {
         crate::cli::DedupStrategy::Memory => {
             // Use sharded deduplicator for parallel performance
             let num_shards = num_cpus::get() * 4;
             let capacity_per_shard = expected_items / num_shards;
-            Box::new(ShardedDeduplicator::with_capacity(num_shards, capacity_per_shard))
+            match dedup_mode {
+                crate::cli::DedupMode::Exact => match hasher_algo {
+                    crate::cli::HasherAlgo::Ahash => {
+                        Box::new(ShardedDeduplicator::with_capacity(num_shards, capacity_per_shard)) as Box<dyn Deduplicator>
+                    }
+                    crate::cli::HasherAlgo::Xxh3 => Box::new(ShardedDeduplicator::with_capacity_and_hasher(
+                        num_shards,
+                        capacity_per_shard,
+                        Xxh3BuildHasher,
+                    )) as Box<dyn Deduplicator>,
+                },
+                crate::cli::DedupMode::Hash64 => {
+                    Box::new(Hash64Deduplicator::with_capacity(num_shards, capacity_per_shard))
+                }
+                crate::cli::DedupMode::Hash128 => {
+                    Box::new(Hash128Deduplicator::with_capacity(num_shards, capacity_per_shard))
+                }
+            }
         }
-        crate::cli::DedupStrategy::Bloom => {
+        crate::cli::DedupStrategy::Bloom => match hasher_algo {
             // Use bloom filter with 0.1% false positive rate
-            Box::new(BloomDeduplicator::new(expected_items, 0.001))
+            crate::cli::HasherAlgo::Ahash => Box::new(BloomDeduplicator::new(expected_items, 0.001)) as Box<dyn Deduplicator>,
+            crate::cli::HasherAlgo::Xxh3 => Box::new(BloomDeduplicator::with_hasher(
+                expected_items,
+                0.001,
+                Xxh3BuildHasher,
+                Xxh3BuildHasher,
+            )) as Box<dyn Deduplicator>,
+        },
+        crate::cli::DedupStrategy::RotatingBloom => {
+            // Same 0.1% target false positive rate, but bounded over the
+            // whole run via rotation instead of growing unbounded.
+            Box::new(RotatingBloomDeduplicator::new(expected_items, 0.001))
         }
         #[cfg(feature = "disk-dedup")]
         crate::cli::DedupStrategy::Disk => {
-            // Disk-based deduplication would be implemented here
-            unimplemented!("Disk-based deduplication requires the 'disk-dedup' feature")
+            let db_path = disk_dedup_path
+                .unwrap_or_else(|| std::env::temp_dir().join("wordlist-filter-dedup.db"));
+            Box::new(DiskDeduplicator::new(db_path, expected_items)?)
         }
-    }
+    })
 }
 
 #[cfg(test)]
@@ -464,4 +1283,170 @@ mod tests {
         assert_eq!(dedup.len(), 2);
         assert!(!dedup.contains("test1")); // Never contains
     }
+
+    #[test]
+    fn test_rotating_bloom_deduplicator() {
+        let dedup = RotatingBloomDeduplicator::new(1000, 0.01);
+
+        assert!(dedup.insert("test1"));
+        assert!(dedup.insert("test2"));
+        assert!(!dedup.insert("test1")); // Should detect duplicate
+
+        assert!(dedup.contains("test1"));
+        assert!(dedup.contains("test2"));
+        assert_eq!(dedup.len(), 2);
+        assert!(dedup.current_fp_rate() < 1.0);
+    }
+
+    #[test]
+    fn test_rotating_bloom_deduplicator_rotates_on_item_count() {
+        let dedup = RotatingBloomDeduplicator::new(1000, 0.01).with_max_items(5);
+
+        for i in 0..10 {
+            dedup.insert(&format!("word{i}"));
+        }
+
+        // Rotation resets the item counter for the new active generation
+        assert!(dedup.items_since_rotation.load(Ordering::Relaxed) < 10);
+        // Recently-inserted words should still be found via the previous generation
+        assert!(dedup.contains("word9"));
+    }
+
+    #[test]
+    fn test_hash64_deduplicator() {
+        let dedup = Hash64Deduplicator::new(4);
+
+        assert!(dedup.insert("test1"));
+        assert!(dedup.insert("test2"));
+        assert!(!dedup.insert("test1")); // Duplicate
+
+        assert_eq!(dedup.len(), 2);
+        assert!(dedup.contains("test1"));
+        assert!(!dedup.contains("test3"));
+    }
+
+    #[test]
+    fn test_hash128_deduplicator() {
+        let dedup = Hash128Deduplicator::new(4);
+
+        assert!(dedup.insert("test1"));
+        assert!(dedup.insert("test2"));
+        assert!(!dedup.insert("test1")); // Duplicate
+
+        assert_eq!(dedup.len(), 2);
+        assert!(dedup.contains("test1"));
+        assert!(!dedup.contains("test3"));
+    }
+
+    /// ahash and xxh3 should agree on correctness; only their speed differs.
+    /// This benchmarks both hashers over the same workload and asserts
+    /// behavioral parity rather than a specific timing bound, since CI
+    /// hardware is too variable to assert on wall-clock numbers.
+    #[test]
+    fn test_memory_deduplicator_ahash_vs_xxh3() {
+        let words: Vec<String> = (0..20_000).map(|i| format!("word-{}", i % 15_000)).collect();
+
+        let ahash_dedup = MemoryDeduplicator::new();
+        let start = Instant::now();
+        let ahash_inserted = words.iter().filter(|w| ahash_dedup.insert(w)).count();
+        let ahash_elapsed = start.elapsed();
+
+        let xxh3_dedup = MemoryDeduplicator::<Xxh3BuildHasher>::with_hasher();
+        let start = Instant::now();
+        let xxh3_inserted = words.iter().filter(|w| xxh3_dedup.insert(w)).count();
+        let xxh3_elapsed = start.elapsed();
+
+        assert_eq!(ahash_inserted, 15_000);
+        assert_eq!(xxh3_inserted, 15_000);
+        assert_eq!(ahash_dedup.len(), xxh3_dedup.len());
+
+        println!(
+            "ahash: {:?} for {} inserts, xxh3: {:?} for {} inserts",
+            ahash_elapsed, ahash_inserted, xxh3_elapsed, xxh3_inserted
+        );
+    }
+
+    #[test]
+    fn test_bloom_deduplicator_ahash_vs_xxh3() {
+        let words: Vec<String> = (0..5_000).map(|i| format!("pw{}", i)).collect();
+
+        let ahash_dedup = BloomDeduplicator::new(5_000, 0.01);
+        let start = Instant::now();
+        for w in &words {
+            ahash_dedup.insert(w);
+        }
+        let ahash_elapsed = start.elapsed();
+
+        let xxh3_dedup = BloomDeduplicator::with_hasher(5_000, 0.01, Xxh3BuildHasher, Xxh3BuildHasher);
+        let start = Instant::now();
+        for w in &words {
+            xxh3_dedup.insert(w);
+        }
+        let xxh3_elapsed = start.elapsed();
+
+        for w in &words {
+            assert!(ahash_dedup.contains(w));
+            assert!(xxh3_dedup.contains(w));
+        }
+
+        println!("ahash bloom: {:?}, xxh3 bloom: {:?}", ahash_elapsed, xxh3_elapsed);
+    }
+
+    #[test]
+    fn test_normalizing_deduplicator_case_fold() {
+        let dedup = NormalizingDeduplicator::new(MemoryDeduplicator::new(), Box::new(CaseFoldNormalizer));
+
+        assert!(dedup.insert("Password"));
+        assert!(!dedup.insert("password")); // Same key after case-folding
+        assert!(!dedup.insert("PASSWORD"));
+
+        assert_eq!(dedup.len(), 1);
+    }
+
+    #[test]
+    fn test_normalizing_deduplicator_leet_speak() {
+        let dedup = NormalizingDeduplicator::new(MemoryDeduplicator::new(), Box::new(LeetSpeakNormalizer));
+
+        assert!(dedup.insert("P@ssw0rd"));
+        assert!(!dedup.insert("passw0rd"));
+        assert!(!dedup.insert("Password"));
+
+        assert_eq!(dedup.len(), 1);
+    }
+
+    #[test]
+    fn test_normalizing_deduplicator_wraps_boxed_dedup() {
+        let inner: Box<dyn Deduplicator> = Box::new(ShardedDeduplicator::new(4));
+        let dedup = NormalizingDeduplicator::new(inner, Box::new(CaseFoldNormalizer));
+
+        assert!(dedup.insert("Password"));
+        assert!(!dedup.insert("PASSWORD"));
+    }
+
+    #[cfg(feature = "disk-dedup")]
+    #[test]
+    fn test_disk_deduplicator_insert_and_resume() {
+        let dir = std::env::temp_dir().join(format!("wordlist-filter-test-{}", std::process::id()));
+
+        {
+            let dedup = DiskDeduplicator::with_batch_size(&dir, 100, 2).unwrap();
+
+            assert!(dedup.insert("alpha"));
+            assert!(dedup.insert("beta"));
+            assert!(!dedup.insert("alpha")); // Duplicate, served from disk (beta's insert hit batch_size and flushed)
+            assert!(dedup.insert("gamma")); // Buffered in the now-empty pending set
+            assert!(!dedup.insert("gamma")); // Duplicate, still in the pending buffer
+
+            assert_eq!(dedup.len(), 3);
+            assert!(dedup.contains("alpha"));
+            assert!(!dedup.contains("delta"));
+        }
+
+        // A fresh instance against the same path resumes the persisted count,
+        // including gamma, which was still pending when the first one dropped.
+        let resumed = DiskDeduplicator::new(&dir, 100).unwrap();
+        assert_eq!(resumed.len(), 3);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
 }
@@ -2,10 +2,11 @@
 //!
 //! Provides automatic detection of file encodings and transcoding to UTF-8.
 
+use crate::compress::{self, Compression};
 use chardetng::EncodingDetector;
 use encoding_rs::Encoding;
 use std::fs::File;
-use std::io::{BufRead, BufReader, Read};
+use std::io::{BufReader, Read};
 use std::path::Path;
 
 /// Result of encoding detection
@@ -33,16 +34,26 @@ impl Default for EncodingInfo {
 pub fn detect_encoding(path: &Path) -> anyhow::Result<EncodingInfo> {
     let file = File::open(path)?;
     let mut reader = BufReader::new(file);
-    
+    detect_encoding_from_reader(&mut reader)
+}
+
+/// Detect the encoding of a compressed file by sampling its decompressed content
+pub fn detect_encoding_compressed(path: &Path, compression: Compression) -> anyhow::Result<EncodingInfo> {
+    let mut reader = compress::open_reader(path, compression)?;
+    detect_encoding_from_reader(&mut reader)
+}
+
+/// Sample up to 64KB from `reader` and guess its encoding
+fn detect_encoding_from_reader(reader: &mut impl Read) -> anyhow::Result<EncodingInfo> {
     // Read sample for detection (first 64KB should be enough)
     let mut sample = vec![0u8; 64 * 1024];
     let bytes_read = reader.read(&mut sample)?;
     sample.truncate(bytes_read);
-    
+
     if bytes_read == 0 {
         return Ok(EncodingInfo::default());
     }
-    
+
     // Check for BOM first
     if let Some(encoding) = detect_bom(&sample) {
         return Ok(EncodingInfo {
@@ -51,13 +62,13 @@ pub fn detect_encoding(path: &Path) -> anyhow::Result<EncodingInfo> {
             encoding,
         });
     }
-    
+
     // Try to detect encoding using chardetng
     let mut detector = EncodingDetector::new();
     detector.feed(&sample, true);
-    
+
     let encoding = detector.guess(None, true);
-    
+
     // Calculate a rough confidence based on whether the content is valid UTF-8
     let confidence = if encoding == encoding_rs::UTF_8 {
         if std::str::from_utf8(&sample).is_ok() {
@@ -68,7 +79,7 @@ pub fn detect_encoding(path: &Path) -> anyhow::Result<EncodingInfo> {
     } else {
         0.8
     };
-    
+
     Ok(EncodingInfo {
         name: encoding.name(),
         confidence,
@@ -113,83 +124,6 @@ impl<R: Read> TranscodingReader<R> {
     }
 }
 
-/// A line iterator that handles different encodings
-pub struct EncodedLineIterator {
-    reader: BufReader<File>,
-    encoding: &'static Encoding,
-    line_buffer: Vec<u8>,
-}
-
-impl EncodedLineIterator {
-    /// Create a new line iterator for a file with automatic encoding detection
-    pub fn new(path: &Path) -> anyhow::Result<Self> {
-        let encoding_info = detect_encoding(path)?;
-        let file = File::open(path)?;
-        
-        Ok(Self {
-            reader: BufReader::with_capacity(64 * 1024, file),
-            encoding: encoding_info.encoding,
-            line_buffer: Vec::with_capacity(4096),
-        })
-    }
-    
-    /// Create with a specific encoding
-    pub fn with_encoding(path: &Path, encoding: &'static Encoding) -> anyhow::Result<Self> {
-        let file = File::open(path)?;
-        
-        Ok(Self {
-            reader: BufReader::with_capacity(64 * 1024, file),
-            encoding,
-            line_buffer: Vec::with_capacity(4096),
-        })
-    }
-    
-    /// Get the detected encoding
-    pub fn encoding(&self) -> &'static Encoding {
-        self.encoding
-    }
-}
-
-impl Iterator for EncodedLineIterator {
-    type Item = anyhow::Result<String>;
-    
-    fn next(&mut self) -> Option<Self::Item> {
-        self.line_buffer.clear();
-        
-        match self.reader.read_until(b'\n', &mut self.line_buffer) {
-            Ok(0) => None, // EOF
-            Ok(_) => {
-                // Remove trailing newline characters
-                while self.line_buffer.last() == Some(&b'\n') 
-                    || self.line_buffer.last() == Some(&b'\r') {
-                    self.line_buffer.pop();
-                }
-                
-                // Decode the line
-                if self.encoding == encoding_rs::UTF_8 {
-                    // Fast path for UTF-8
-                    match String::from_utf8(self.line_buffer.clone()) {
-                        Ok(s) => Some(Ok(s)),
-                        Err(e) => {
-                            // Try lossy conversion for invalid UTF-8
-                            Some(Ok(String::from_utf8_lossy(e.as_bytes()).into_owned()))
-                        }
-                    }
-                } else {
-                    // Transcode from other encodings
-                    let (decoded, _, had_errors) = self.encoding.decode(&self.line_buffer);
-                    if had_errors {
-                        // Log warning but continue
-                        log::warn!("Encoding errors in line, using lossy conversion");
-                    }
-                    Some(Ok(decoded.into_owned()))
-                }
-            }
-            Err(e) => Some(Err(e.into())),
-        }
-    }
-}
-
 /// Memory-mapped file reader for large files
 pub struct MmapLineIterator {
     mmap: memmap2::Mmap,
@@ -274,6 +208,127 @@ impl Iterator for MmapLineIterator {
     }
 }
 
+/// Line iterator over a (possibly compressed) stream that can't be
+/// memory-mapped, since the bytes on disk aren't the plaintext bytes.
+///
+/// Rather than running `Encoding::decode` per line, this feeds whole read
+/// chunks through a persistent `encoding_rs::Decoder`, which tracks any
+/// multibyte sequence split across a chunk boundary internally. Lines are
+/// then split out of the transcoded chunk; [`Self::next_line_into`] writes
+/// into a caller-owned `String` so no per-line heap allocation happens on
+/// the hot path, and the `Iterator` impl below is a thin allocating wrapper
+/// around it for callers that want owned `String`s.
+pub struct CompressedLineIterator {
+    reader: BufReader<Box<dyn Read>>,
+    encoding: &'static Encoding,
+    decoder: encoding_rs::Decoder,
+    /// Reusable raw-byte read buffer, refilled one chunk at a time.
+    raw_buf: Vec<u8>,
+    /// Reusable transcoded-chunk buffer; `decoded_pos` tracks how much of it
+    /// has already been split into lines.
+    decoded: String,
+    decoded_pos: usize,
+    /// A line fragment left over when a chunk ends mid-line.
+    pending_line: String,
+    eof: bool,
+}
+
+impl CompressedLineIterator {
+    /// Create a new line iterator for a compressed file, auto-detecting the
+    /// encoding of its decompressed content.
+    pub fn new(path: &Path, compression: Compression) -> anyhow::Result<Self> {
+        let encoding_info = detect_encoding_compressed(path, compression)?;
+        let reader = compress::open_reader(path, compression)?;
+        let encoding = encoding_info.encoding;
+
+        Ok(Self {
+            reader: BufReader::with_capacity(64 * 1024, reader),
+            encoding,
+            decoder: encoding.new_decoder(),
+            raw_buf: vec![0u8; 64 * 1024],
+            decoded: String::new(),
+            decoded_pos: 0,
+            pending_line: String::new(),
+            eof: false,
+        })
+    }
+
+    /// Get the detected encoding
+    pub fn encoding(&self) -> &'static Encoding {
+        self.encoding
+    }
+
+    /// Read the next line into `out`, clearing and reusing its allocation.
+    /// Returns `Ok(false)` at EOF instead of yielding an empty line.
+    pub fn next_line_into(&mut self, out: &mut String) -> anyhow::Result<bool> {
+        loop {
+            if self.decoded_pos < self.decoded.len() {
+                let rest = &self.decoded[self.decoded_pos..];
+                if let Some(idx) = rest.find('\n') {
+                    let line = rest[..idx].strip_suffix('\r').unwrap_or(&rest[..idx]);
+                    out.clear();
+                    if !self.pending_line.is_empty() {
+                        out.push_str(&self.pending_line);
+                        self.pending_line.clear();
+                    }
+                    out.push_str(line);
+                    self.decoded_pos += idx + 1;
+                    return Ok(true);
+                } else {
+                    // No newline in the remainder of this chunk -- carry it
+                    // over and pull in the next chunk before deciding where
+                    // the line actually ends.
+                    self.pending_line.push_str(rest);
+                    self.decoded_pos = self.decoded.len();
+                }
+            }
+
+            if self.eof {
+                if !self.pending_line.is_empty() {
+                    out.clear();
+                    out.push_str(&self.pending_line);
+                    self.pending_line.clear();
+                    return Ok(true);
+                }
+                return Ok(false);
+            }
+
+            let n = self.reader.read(&mut self.raw_buf)?;
+            let last = n == 0;
+            self.eof = last;
+
+            self.decoded.clear();
+            self.decoded_pos = 0;
+            // Flush any trailing replacement character on the final,
+            // zero-length call by still passing `last = true` through.
+            self.decoded.reserve(self.decoder.max_utf8_buffer_length(n).unwrap_or(n * 4));
+            self.decoder.decode_to_string(&self.raw_buf[..n], &mut self.decoded, last);
+        }
+    }
+}
+
+impl Iterator for CompressedLineIterator {
+    type Item = anyhow::Result<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut line = String::new();
+        match self.next_line_into(&mut line) {
+            Ok(true) => Some(Ok(line)),
+            Ok(false) => None,
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+/// Open the right line iterator for `path`: memory-mapped for plain text,
+/// or a streaming decompressing reader for `.gz`/`.zst`/`.bz2` sources.
+pub fn open_line_iterator(path: &Path) -> anyhow::Result<Box<dyn Iterator<Item = anyhow::Result<String>>>> {
+    match Compression::from_extension(path) {
+        Compression::None => Ok(Box::new(MmapLineIterator::new(path)?)),
+        compression => Ok(Box::new(CompressedLineIterator::new(path, compression)?)),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -296,10 +351,29 @@ mod tests {
         writeln!(file, "line1").unwrap();
         writeln!(file, "line2").unwrap();
         writeln!(file, "line3").unwrap();
-        
-        let iter = EncodedLineIterator::new(file.path()).unwrap();
+
+        let iter = CompressedLineIterator::new(file.path(), Compression::None).unwrap();
         let lines: Vec<_> = iter.filter_map(|r| r.ok()).collect();
-        
+
         assert_eq!(lines, vec!["line1", "line2", "line3"]);
     }
+
+    #[test]
+    fn test_compressed_line_iterator_gzip() {
+        use flate2::write::GzEncoder;
+        use std::io::Write as _;
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("wordlist.txt.gz");
+        let file = std::fs::File::create(&path).unwrap();
+        let mut encoder = GzEncoder::new(file, flate2::Compression::default());
+        writeln!(encoder, "password").unwrap();
+        writeln!(encoder, "letmein").unwrap();
+        encoder.finish().unwrap();
+
+        let iter = CompressedLineIterator::new(&path, Compression::Gzip).unwrap();
+        let lines: Vec<_> = iter.filter_map(|r| r.ok()).collect();
+
+        assert_eq!(lines, vec!["password", "letmein"]);
+    }
 }
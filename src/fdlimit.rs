@@ -0,0 +1,78 @@
+//! Raises the process's open-file-descriptor limit on startup.
+//!
+//! A recursive run over a large wordlist directory can hand thousands of
+//! files to the rayon thread pool; once the soft `RLIMIT_NOFILE` is hit the
+//! OS starts failing opens with "Too many open files" mid-run. Call
+//! [`raise_fd_limit`] once at startup, before any threads are spawned, to
+//! push the soft limit up toward the hard limit.
+
+#[cfg(unix)]
+pub fn raise_fd_limit() -> Option<u64> {
+    unsafe {
+        let mut limits = libc::rlimit {
+            rlim_cur: 0,
+            rlim_max: 0,
+        };
+        if libc::getrlimit(libc::RLIMIT_NOFILE, &mut limits) != 0 {
+            return None;
+        }
+
+        // macOS reports RLIM_INFINITY as its hard limit but silently caps
+        // the soft limit at OPEN_MAX (per-process), which `setrlimit`
+        // doesn't surface -- it just fails the call with EINVAL if you ask
+        // for more. Fold that cap in up front so we ask for a limit the
+        // kernel will actually accept.
+        #[cfg(target_os = "macos")]
+        let rlim_max = {
+            let open_max = macos_open_max().unwrap_or(limits.rlim_max);
+            limits.rlim_max.min(open_max)
+        };
+        #[cfg(not(target_os = "macos"))]
+        let rlim_max = limits.rlim_max;
+
+        if limits.rlim_cur >= rlim_max {
+            return Some(limits.rlim_cur);
+        }
+
+        let raised = libc::rlimit {
+            rlim_cur: rlim_max,
+            rlim_max: limits.rlim_max,
+        };
+        if libc::setrlimit(libc::RLIMIT_NOFILE, &raised) != 0 {
+            return Some(limits.rlim_cur);
+        }
+
+        Some(rlim_max)
+    }
+}
+
+#[cfg(not(unix))]
+pub fn raise_fd_limit() -> Option<u64> {
+    None
+}
+
+/// Query `KERN_MAXFILESPERPROC` for the per-process open-file ceiling macOS
+/// will actually honor, since its `rlim_max` for `RLIMIT_NOFILE` lies.
+#[cfg(target_os = "macos")]
+fn macos_open_max() -> Option<u64> {
+    unsafe {
+        let mut mib = [libc::CTL_KERN, libc::KERN_MAXFILESPERPROC];
+        let mut open_max: libc::c_int = 0;
+        let mut size = std::mem::size_of::<libc::c_int>();
+
+        let ret = libc::sysctl(
+            mib.as_mut_ptr(),
+            mib.len() as libc::c_uint,
+            &mut open_max as *mut _ as *mut libc::c_void,
+            &mut size,
+            std::ptr::null_mut(),
+            0,
+        );
+
+        if ret == 0 {
+            Some(open_max as u64)
+        } else {
+            None
+        }
+    }
+}
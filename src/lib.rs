@@ -28,15 +28,27 @@
 //!
 //! ```rust,no_run
 //! use wordlist_filter::processor::{Processor, ProcessorConfig};
+//! use wordlist_filter::cli::{DedupMode, DedupStrategy, HasherAlgo, NormalizeMode};
+//! use wordlist_filter::compress::Compression;
 //! use std::path::PathBuf;
 //!
 //! let config = ProcessorConfig {
 //!     lengths: Some(vec![8, 10, 12]),
 //!     pattern: None,
+//!     categorize: false,
 //!     single_file: false,
 //!     output_dir: PathBuf::from("./output"),
 //!     output_name: "filtered.txt".to_string(),
 //!     recursive: false,
+//!     follow_symlinks: false,
+//!     exclude: vec![],
+//!     include: vec![],
+//!     min_size: None,
+//!     max_size: None,
+//!     dedup_strategy: DedupStrategy::Memory,
+//!     dedup_mode: DedupMode::Exact,
+//!     memory_limit: 8_000_000_000,
+//!     disk_dedup_path: None,
 //!     no_dedup: false,
 //!     buffer_size: 64 * 1024 * 1024,
 //!     extensions: vec!["txt".to_string()],
@@ -44,6 +56,14 @@
 //!     quiet: false,
 //!     verbose: false,
 //!     sort_output: false,
+//!     tempdir: std::env::temp_dir(),
+//!     compress: Compression::None,
+//!     deny_list: None,
+//!     allow_list: None,
+//!     substring_case_insensitive: false,
+//!     hasher_algo: HasherAlgo::Ahash,
+//!     normalize_mode: NormalizeMode::None,
+//!     json: false,
 //! };
 //!
 //! let processor = Processor::new(config);
@@ -51,12 +71,16 @@
 //! ```
 
 pub mod cli;
+pub mod completions;
+pub mod compress;
 pub mod dedup;
 pub mod encoding;
+pub mod fdlimit;
 pub mod filter;
 pub mod output;
 pub mod processor;
 pub mod progress;
+pub mod sort;
 
 pub use cli::Args;
 pub use processor::{Processor, ProcessorConfig};
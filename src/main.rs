@@ -7,13 +7,29 @@ use colored::*;
 use std::process;
 
 use wordlist_filter::cli::Args;
+use wordlist_filter::completions::{generate_man, print_completions};
+use wordlist_filter::fdlimit::raise_fd_limit;
 use wordlist_filter::processor::{Processor, ProcessorConfig};
 use wordlist_filter::progress::{print_banner, print_error, print_header, print_info};
 
 fn main() {
     // Parse command-line arguments
     let args = Args::parse();
-    
+
+    // Shell completions and man-page generation are standalone utility
+    // modes: handle them before logging/validation and exit immediately.
+    if let Some(shell) = args.completions {
+        print_completions(shell);
+        return;
+    }
+    if let Some(ref dir) = args.generate_man {
+        if let Err(e) = generate_man(dir) {
+            print_error(&format!("{}", e));
+            process::exit(1);
+        }
+        return;
+    }
+
     // Set up logging
     if args.verbose {
         std::env::set_var("RUST_LOG", "debug");
@@ -21,7 +37,16 @@ fn main() {
         std::env::set_var("RUST_LOG", "info");
     }
     env_logger::init();
-    
+
+    // Raise the open-file-descriptor limit before spawning any threads, so a
+    // recursive run over many files doesn't hit "Too many open files".
+    let raised_fd_limit = raise_fd_limit();
+    if args.verbose && !args.json {
+        if let Some(limit) = raised_fd_limit {
+            print_info(&format!("Open-file-descriptor limit: {}", limit));
+        }
+    }
+
     // Configure thread pool
     if let Some(threads) = args.threads {
         rayon::ThreadPoolBuilder::new()
@@ -29,7 +54,7 @@ fn main() {
             .build_global()
             .ok();
     }
-    
+
     // Run the application
     if let Err(e) = run(args) {
         print_error(&format!("{}", e));
@@ -46,59 +71,67 @@ fn main() {
 }
 
 fn run(args: Args) -> anyhow::Result<()> {
-    // Print banner unless quiet mode
-    if !args.quiet {
+    // Print banner unless quiet or JSON mode
+    if !args.quiet && !args.json {
         print_banner();
     }
-    
+
+    // `required_unless_present_any` on `--input` guarantees this is set by
+    // the time we get here, since the `--completions`/`--generate-man` exits
+    // above already handled the only cases where it's allowed to be absent.
+    let input = args
+        .input
+        .clone()
+        .expect("clap enforces --input unless --completions/--generate-man is passed");
+
     // Validate arguments
-    validate_args(&args)?;
-    
+    validate_args(&args, &input)?;
+
     // Create processor configuration
     let config = ProcessorConfig::from_args(&args)?;
-    
+
     // Show configuration
-    if !args.quiet && args.verbose {
-        print_config(&args, &config);
+    if !args.quiet && !args.json && args.verbose {
+        print_config(&args, &input, &config);
     }
-    
+
     // Create and run processor
     let processor = Processor::new(config);
-    processor.process(&args.input)?;
-    
+    processor.process(&input)?;
+
     Ok(())
 }
 
 /// Validate command-line arguments
-fn validate_args(args: &Args) -> anyhow::Result<()> {
+fn validate_args(args: &Args, input: &std::path::Path) -> anyhow::Result<()> {
     // Check that input exists
-    if !args.input.exists() {
-        anyhow::bail!("Input path does not exist: {:?}", args.input);
+    if !input.exists() {
+        anyhow::bail!("Input path does not exist: {:?}", input);
     }
-    
+
     // Check that we have at least one filter
-    if args.length.is_none() && args.pattern.is_none() {
-        anyhow::bail!("At least one filter must be specified: --length or --pattern");
+    if args.length.is_none() && args.pattern.is_none() && args.deny_list.is_none() && args.allow_list.is_none() && !args.categorize {
+        anyhow::bail!("At least one filter must be specified: --length, --pattern, --deny-list, --allow-list, or --categorize");
     }
-    
+
     // Validate regex pattern if provided
     if let Some(ref pattern) = args.pattern {
         wordlist_filter::filter::validate_pattern(pattern)?;
     }
-    
+
     // Validate length specification
     if let Some(ref length) = args.length {
         args.parse_lengths()?;
     }
-    
+
     Ok(())
 }
 
 /// Print configuration summary
-fn print_config(args: &Args, config: &ProcessorConfig) {
+fn print_config(args: &Args, input: &std::path::Path, config: &ProcessorConfig) {
     print_header("Configuration");
-    
-    print_info(&format!("Input:        {:?}", args.input));
+
+    print_info(&format!("Input:        {:?}", input));
     print_info(&format!("Output dir:   {:?}", config.output_dir));
     
     if let Some(ref lengths) = config.lengths {
@@ -108,7 +141,15 @@ fn print_config(args: &Args, config: &ProcessorConfig) {
     if let Some(ref pattern) = config.pattern {
         print_info(&format!("Pattern:      {}", pattern));
     }
-    
+
+    if let Some(ref deny) = config.deny_list {
+        print_info(&format!("Deny list:    {} substrings", deny.len()));
+    }
+
+    if let Some(ref allow) = config.allow_list {
+        print_info(&format!("Allow list:   {} substrings", allow.len()));
+    }
+
     print_info(&format!("Single file:  {}", config.single_file));
     print_info(&format!("Recursive:    {}", config.recursive));
     print_info(&format!("Dedup:        {}", !config.no_dedup));
@@ -2,18 +2,19 @@
 //!
 //! Handles writing filtered words to output files with buffering for performance.
 
+use crate::compress::{CompressedWriter, Compression};
 use std::collections::HashMap;
-use std::fs::{File, OpenOptions};
-use std::io::{BufWriter, Write};
+use std::fs::OpenOptions;
+use std::io::Write;
 use std::path::{Path, PathBuf};
 use std::sync::Mutex;
 
 /// Default buffer size for file writing (64MB)
 const DEFAULT_BUFFER_SIZE: usize = 64 * 1024 * 1024;
 
-/// Output file writer with buffering
+/// Output file writer with buffering, optionally compressing what it writes
 pub struct OutputWriter {
-    writer: BufWriter<File>,
+    writer: CompressedWriter,
     path: PathBuf,
     lines_written: u64,
     bytes_written: u64,
@@ -21,15 +22,15 @@ pub struct OutputWriter {
 
 impl OutputWriter {
     /// Create a new output writer
-    pub fn new(path: PathBuf, buffer_size: usize) -> anyhow::Result<Self> {
+    pub fn new(path: PathBuf, buffer_size: usize, compression: Compression) -> anyhow::Result<Self> {
         let file = OpenOptions::new()
             .write(true)
             .create(true)
             .truncate(true)
             .open(&path)?;
-        
-        let writer = BufWriter::with_capacity(buffer_size, file);
-        
+
+        let writer = CompressedWriter::new(file, buffer_size, compression)?;
+
         Ok(Self {
             writer,
             path,
@@ -87,8 +88,8 @@ pub struct SyncOutputWriter {
 }
 
 impl SyncOutputWriter {
-    pub fn new(path: PathBuf, buffer_size: usize) -> anyhow::Result<Self> {
-        let writer = OutputWriter::new(path, buffer_size)?;
+    pub fn new(path: PathBuf, buffer_size: usize, compression: Compression) -> anyhow::Result<Self> {
+        let writer = OutputWriter::new(path, buffer_size, compression)?;
         Ok(Self {
             inner: Mutex::new(writer),
         })
@@ -126,29 +127,37 @@ pub struct MultiOutputManager {
     output_dir: PathBuf,
     prefix: String,
     buffer_size: usize,
+    compression: Compression,
 }
 
 impl MultiOutputManager {
     /// Create a new multi-output manager
-    pub fn new(output_dir: PathBuf, prefix: &str, buffer_size: usize) -> Self {
+    pub fn new(output_dir: PathBuf, prefix: &str, buffer_size: usize, compression: Compression) -> Self {
         Self {
             writers: HashMap::new(),
             output_dir,
             prefix: prefix.to_string(),
             buffer_size,
+            compression,
         }
     }
-    
+
+    /// Build the output path for a given length, with the compression extension applied
+    fn path_for_length(&self, length: usize) -> PathBuf {
+        let path = self.output_dir.join(format!("{}_len{}.txt", self.prefix, length));
+        crate::compress::append_extension(&path, self.compression)
+    }
+
     /// Initialize writers for specific lengths
     pub fn init_lengths(&mut self, lengths: &[usize]) -> anyhow::Result<()> {
         for &length in lengths {
-            let path = self.output_dir.join(format!("{}_len{}.txt", self.prefix, length));
-            let writer = SyncOutputWriter::new(path, self.buffer_size)?;
+            let path = self.path_for_length(length);
+            let writer = SyncOutputWriter::new(path, self.buffer_size, self.compression)?;
             self.writers.insert(length, writer);
         }
         Ok(())
     }
-    
+
     /// Write a line to the appropriate length file
     pub fn write_line(&self, line: &str, length: usize) -> anyhow::Result<()> {
         if let Some(writer) = self.writers.get(&length) {
@@ -156,12 +165,12 @@ impl MultiOutputManager {
         }
         Ok(())
     }
-    
+
     /// Get or create a writer for a specific length
     pub fn get_or_create(&mut self, length: usize) -> anyhow::Result<&SyncOutputWriter> {
         if !self.writers.contains_key(&length) {
-            let path = self.output_dir.join(format!("{}_len{}.txt", self.prefix, length));
-            let writer = SyncOutputWriter::new(path, self.buffer_size)?;
+            let path = self.path_for_length(length);
+            let writer = SyncOutputWriter::new(path, self.buffer_size, self.compression)?;
             self.writers.insert(length, writer);
         }
         Ok(self.writers.get(&length).unwrap())
@@ -194,14 +203,85 @@ impl MultiOutputManager {
     }
 }
 
+/// Manager for multiple output files, one per pattern-set category
+/// (e.g. `complex_password.txt`, `digits_only.txt`) instead of one per length.
+pub struct PatternOutputManager {
+    writers: HashMap<String, SyncOutputWriter>,
+    output_dir: PathBuf,
+    buffer_size: usize,
+    compression: Compression,
+}
+
+impl PatternOutputManager {
+    /// Create a new pattern-set output manager
+    pub fn new(output_dir: PathBuf, buffer_size: usize, compression: Compression) -> Self {
+        Self {
+            writers: HashMap::new(),
+            output_dir,
+            buffer_size,
+            compression,
+        }
+    }
+
+    /// Build the output path for a given category, with the compression extension applied
+    fn path_for_category(&self, name: &str) -> PathBuf {
+        let path = self.output_dir.join(format!("{}.txt", name));
+        crate::compress::append_extension(&path, self.compression)
+    }
+
+    /// Initialize writers for specific categories
+    pub fn init_categories(&mut self, names: &[String]) -> anyhow::Result<()> {
+        for name in names {
+            let path = self.path_for_category(name);
+            let writer = SyncOutputWriter::new(path, self.buffer_size, self.compression)?;
+            self.writers.insert(name.clone(), writer);
+        }
+        Ok(())
+    }
+
+    /// Write a line to the appropriate category file
+    pub fn write_line(&self, line: &str, name: &str) -> anyhow::Result<()> {
+        if let Some(writer) = self.writers.get(name) {
+            writer.write_line(line)?;
+        }
+        Ok(())
+    }
+
+    /// Flush all writers
+    pub fn flush_all(&self) -> anyhow::Result<()> {
+        for writer in self.writers.values() {
+            writer.flush()?;
+        }
+        Ok(())
+    }
+
+    /// Get statistics for all outputs
+    pub fn get_stats(&self) -> Vec<(String, u64, u64)> {
+        let mut stats: Vec<_> = self.writers.iter()
+            .map(|(name, w)| (name.clone(), w.lines_written(), w.bytes_written()))
+            .collect();
+        stats.sort_by(|a, b| a.0.cmp(&b.0));
+        stats
+    }
+
+    /// Get output paths
+    pub fn get_paths(&self) -> Vec<(String, PathBuf)> {
+        let mut paths: Vec<_> = self.writers.iter()
+            .map(|(name, w)| (name.clone(), w.path()))
+            .collect();
+        paths.sort_by(|a, b| a.0.cmp(&b.0));
+        paths
+    }
+}
+
 /// Single output manager for combined output
 pub struct SingleOutputManager {
     writer: SyncOutputWriter,
 }
 
 impl SingleOutputManager {
-    pub fn new(path: PathBuf, buffer_size: usize) -> anyhow::Result<Self> {
-        let writer = SyncOutputWriter::new(path, buffer_size)?;
+    pub fn new(path: PathBuf, buffer_size: usize, compression: Compression) -> anyhow::Result<Self> {
+        let writer = SyncOutputWriter::new(path, buffer_size, compression)?;
         Ok(Self { writer })
     }
     
@@ -236,13 +316,13 @@ pub enum OutputMode {
 
 impl OutputMode {
     /// Create single output mode
-    pub fn single(path: PathBuf, buffer_size: usize) -> anyhow::Result<Self> {
-        Ok(Self::Single(SingleOutputManager::new(path, buffer_size)?))
+    pub fn single(path: PathBuf, buffer_size: usize, compression: Compression) -> anyhow::Result<Self> {
+        Ok(Self::Single(SingleOutputManager::new(path, buffer_size, compression)?))
     }
-    
+
     /// Create multi output mode
-    pub fn multi(output_dir: PathBuf, prefix: &str, lengths: &[usize], buffer_size: usize) -> anyhow::Result<Self> {
-        let mut manager = MultiOutputManager::new(output_dir, prefix, buffer_size);
+    pub fn multi(output_dir: PathBuf, prefix: &str, lengths: &[usize], buffer_size: usize, compression: Compression) -> anyhow::Result<Self> {
+        let mut manager = MultiOutputManager::new(output_dir, prefix, buffer_size, compression);
         manager.init_lengths(lengths)?;
         Ok(Self::Multi(manager))
     }
@@ -291,7 +371,7 @@ mod tests {
         let temp_dir = TempDir::new().unwrap();
         let path = temp_dir.path().join("test.txt");
         
-        let mut writer = OutputWriter::new(path.clone(), 1024).unwrap();
+        let mut writer = OutputWriter::new(path.clone(), 1024, Compression::None).unwrap();
         writer.write_line("hello").unwrap();
         writer.write_line("world").unwrap();
         writer.flush().unwrap();
@@ -309,7 +389,8 @@ mod tests {
         let mut manager = MultiOutputManager::new(
             temp_dir.path().to_path_buf(),
             "wordlist",
-            1024
+            1024,
+            Compression::None
         );
         
         manager.init_lengths(&[8, 10]).unwrap();
@@ -322,6 +403,27 @@ mod tests {
         assert_eq!(stats.len(), 2);
     }
     
+    #[test]
+    fn test_pattern_output_manager() {
+        let temp_dir = TempDir::new().unwrap();
+
+        let mut manager = PatternOutputManager::new(
+            temp_dir.path().to_path_buf(),
+            1024,
+            Compression::None
+        );
+
+        manager.init_categories(&["digits_only".to_string(), "letters_only".to_string()]).unwrap();
+
+        manager.write_line("12345", "digits_only").unwrap();
+        manager.write_line("password", "letters_only").unwrap();
+        manager.write_line("password", "digits_only").unwrap(); // same word, different category
+        manager.flush_all().unwrap();
+
+        let stats = manager.get_stats();
+        assert_eq!(stats.len(), 2);
+    }
+
     #[test]
     fn test_generate_output_name() {
         let input = Path::new("/path/to/rockyou.txt");
@@ -2,15 +2,21 @@
 //!
 //! Handles parallel processing of large wordlist files with filtering and deduplication.
 
-use crate::cli::Args;
-use crate::dedup::{create_deduplicator, Deduplicator, NoOpDeduplicator, ShardedDeduplicator};
-use crate::encoding::MmapLineIterator;
-use crate::filter::{FilterConfig, MultiLengthRouter, SingleLengthFilter};
-use crate::output::{ensure_output_dir, MultiOutputManager, OutputMode, SingleOutputManager};
+use crate::cli::{Args, DedupMode, DedupStrategy, HasherAlgo, NormalizeMode};
+use crate::compress::Compression;
+use crate::dedup::{
+    create_deduplicator_with_disk_path, CaseFoldNormalizer, Deduplicator, KeyNormalizer, LeetSpeakNormalizer,
+    NoOpDeduplicator, NormalizingDeduplicator, UnicodeForm, UnicodeNormalizer,
+};
+use crate::encoding::open_line_iterator;
+use crate::filter::{FilterConfig, MultiLengthRouter, PatternSetFilter, SetCombinator, SingleLengthFilter};
+use crate::output::{ensure_output_dir, MultiOutputManager, OutputMode, PatternOutputManager, SingleOutputManager};
 use crate::progress::{create_bytes_progress_bar, print_bullet, print_error, print_header, print_info, print_success, print_warning, ProcessingStats};
+use crate::sort::ExternalSorter;
 
 use bytesize::ByteSize;
 use colored::*;
+use glob::Pattern;
 use rayon::prelude::*;
 use std::fs;
 use std::path::{Path, PathBuf};
@@ -18,14 +24,60 @@ use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use walkdir::WalkDir;
 
+/// Rough average ratio of uncompressed to compressed size for text wordlists,
+/// used to keep the dedup capacity estimate sane when the input is gzip/zstd/bz2.
+const COMPRESSED_SIZE_RATIO: u64 = 4;
+
+/// Estimate the total uncompressed size of `files`, scaling compressed files
+/// up by [`COMPRESSED_SIZE_RATIO`] so deduplicator capacity isn't sized off
+/// their on-disk (compressed) size.
+fn estimate_uncompressed_size(files: &[(PathBuf, u64)]) -> u64 {
+    files.iter()
+        .map(|(path, size)| {
+            if Compression::from_extension(path) == Compression::None {
+                *size
+            } else {
+                size.saturating_mul(COMPRESSED_SIZE_RATIO)
+            }
+        })
+        .sum()
+}
+
+/// `--disk-dedup-path`, or `None` unconditionally when the `disk-dedup`
+/// feature (and so the flag itself) isn't compiled in.
+#[cfg(feature = "disk-dedup")]
+fn resolved_disk_dedup_path(args: &Args) -> Option<PathBuf> {
+    args.disk_dedup_path.clone()
+}
+
+#[cfg(not(feature = "disk-dedup"))]
+fn resolved_disk_dedup_path(_args: &Args) -> Option<PathBuf> {
+    None
+}
+
 /// Processor configuration
 pub struct ProcessorConfig {
     pub lengths: Option<Vec<usize>>,
     pub pattern: Option<String>,
+    pub categorize: bool,
     pub single_file: bool,
     pub output_dir: PathBuf,
     pub output_name: String,
     pub recursive: bool,
+    pub follow_symlinks: bool,
+    pub exclude: Vec<String>,
+    pub include: Vec<String>,
+    pub min_size: Option<u64>,
+    pub max_size: Option<u64>,
+    pub dedup_strategy: DedupStrategy,
+    pub dedup_mode: DedupMode,
+    pub hasher_algo: HasherAlgo,
+    pub memory_limit: usize,
+    /// On-disk dedup database path (`--disk-dedup-path`), used only when
+    /// `dedup_strategy` is [`DedupStrategy::Disk`]. Always `None` when the
+    /// `disk-dedup` feature is off, since the flag doesn't exist then.
+    pub disk_dedup_path: Option<PathBuf>,
+    pub normalize_mode: NormalizeMode,
     pub no_dedup: bool,
     pub buffer_size: usize,
     pub extensions: Vec<String>,
@@ -33,6 +85,12 @@ pub struct ProcessorConfig {
     pub quiet: bool,
     pub verbose: bool,
     pub sort_output: bool,
+    pub tempdir: PathBuf,
+    pub compress: Compression,
+    pub deny_list: Option<Vec<String>>,
+    pub allow_list: Option<Vec<String>>,
+    pub substring_case_insensitive: bool,
+    pub json: bool,
 }
 
 impl ProcessorConfig {
@@ -40,17 +98,37 @@ impl ProcessorConfig {
         Ok(Self {
             lengths: args.parse_lengths()?,
             pattern: args.pattern.clone(),
+            categorize: args.categorize,
             single_file: args.single_file,
             output_dir: args.get_output_dir(),
             output_name: args.output_name.clone(),
             recursive: args.recursive,
+            follow_symlinks: args.follow_symlinks,
+            exclude: args.exclude.clone(),
+            include: args.include.clone(),
+            min_size: args.parse_min_size()?,
+            max_size: args.parse_max_size()?,
+            dedup_strategy: args.dedup_strategy,
+            dedup_mode: args.dedup_mode,
+            hasher_algo: args.hasher,
+            memory_limit: args.parse_memory_limit()?,
+            disk_dedup_path: resolved_disk_dedup_path(args),
+            normalize_mode: args.normalize,
             no_dedup: args.no_dedup,
             buffer_size: args.parse_buffer_size()?,
             extensions: args.get_extensions(),
             dry_run: args.dry_run,
-            quiet: args.quiet,
+            // --json suppresses the banner and progress bars just like
+            // --quiet does, so its summary is the only thing printed.
+            quiet: args.quiet || args.json,
             verbose: args.verbose,
             sort_output: args.sort,
+            tempdir: args.get_tempdir(),
+            compress: args.compression(),
+            deny_list: args.read_deny_list()?,
+            allow_list: args.read_allow_list()?,
+            substring_case_insensitive: args.substring_case_insensitive,
+            json: args.json,
         })
     }
 }
@@ -63,10 +141,10 @@ pub struct Processor {
 
 impl Processor {
     pub fn new(config: ProcessorConfig) -> Self {
-        Self {
-            config,
-            stats: Arc::new(ProcessingStats::new()),
-        }
+        let stats = Arc::new(ProcessingStats::new());
+        crate::progress::spawn_snapshot_watcher(Arc::clone(&stats));
+
+        Self { config, stats }
     }
     
     /// Process input (file or directory)
@@ -103,7 +181,9 @@ impl Processor {
         }
         
         // Process based on mode
-        if self.config.single_file {
+        if self.config.categorize {
+            self.process_pattern_set(&files)?;
+        } else if self.config.single_file {
             self.process_single_output(&files)?;
         } else if let Some(ref lengths) = self.config.lengths {
             if lengths.len() == 1 {
@@ -117,166 +197,310 @@ impl Processor {
         }
         
         // Print statistics
-        if !self.config.quiet {
+        if self.config.json {
+            self.stats.print_summary_json();
+        } else if !self.config.quiet {
             self.stats.print_summary();
         }
-        
+
         Ok(())
     }
     
+    /// Wrap `dedup` in a [`NormalizingDeduplicator`] if `--normalize` selected
+    /// a canonical-key mode, so near-duplicate words collapse to one entry.
+    /// A no-op under [`NormalizeMode::None`] (the default).
+    fn wrap_normalized(&self, dedup: Box<dyn Deduplicator>) -> Box<dyn Deduplicator> {
+        let normalizer: Box<dyn KeyNormalizer> = match self.config.normalize_mode {
+            NormalizeMode::None => return dedup,
+            NormalizeMode::CaseFold => Box::new(CaseFoldNormalizer),
+            NormalizeMode::Leet => Box::new(LeetSpeakNormalizer),
+            NormalizeMode::UnicodeNfc => Box::new(UnicodeNormalizer::new(UnicodeForm::Nfc)),
+            NormalizeMode::UnicodeNfkc => Box::new(UnicodeNormalizer::new(UnicodeForm::Nfkc)),
+        };
+        Box::new(NormalizingDeduplicator::new(dedup, normalizer))
+    }
+
+    /// Build the deduplicator selected by `--dedup-strategy`/`--dedup-mode`/
+    /// `--hasher`, sized for `expected_items`.
+    ///
+    /// `disk_path_suffix` disambiguates `--disk-dedup-path` (or, without it,
+    /// the OS-temp-dir default) when several deduplicators run concurrently
+    /// against it -- one per length/category
+    /// -- so [`DedupStrategy::Disk`] runs don't collide on the same on-disk
+    /// database the way unsuffixed `ExternalSorter` run files once did. Pass
+    /// `None` where only one deduplicator is ever live at a time.
+    fn build_deduplicator(&self, expected_items: usize, disk_path_suffix: Option<&str>) -> anyhow::Result<Box<dyn Deduplicator>> {
+        let disk_dedup_path = match (&self.config.disk_dedup_path, disk_path_suffix) {
+            (Some(path), Some(suffix)) => Some(PathBuf::from(format!("{}-{}", path.display(), suffix))),
+            (Some(path), None) => Some(path.clone()),
+            (None, Some(suffix)) => Some(std::env::temp_dir().join(format!("wordlist-filter-dedup.db-{}", suffix))),
+            (None, None) => None,
+        };
+
+        create_deduplicator_with_disk_path(
+            self.config.dedup_strategy,
+            self.config.dedup_mode,
+            self.config.hasher_algo,
+            expected_items,
+            self.config.memory_limit,
+            disk_dedup_path,
+        )
+    }
+
     /// Collect all files to process
     fn collect_files(&self, input: &Path) -> anyhow::Result<Vec<(PathBuf, u64)>> {
         let mut files = Vec::new();
-        
+
         if input.is_file() {
             let size = fs::metadata(input)?.len();
             files.push((input.to_path_buf(), size));
             self.stats.add_file(size);
         } else if input.is_dir() {
+            let exclude: Vec<Pattern> = self.config.exclude.iter()
+                .map(|g| Pattern::new(g).map_err(|e| anyhow::anyhow!("Invalid --exclude glob '{}': {}", g, e)))
+                .collect::<anyhow::Result<_>>()?;
+            let include: Vec<Pattern> = self.config.include.iter()
+                .map(|g| Pattern::new(g).map_err(|e| anyhow::anyhow!("Invalid --include glob '{}': {}", g, e)))
+                .collect::<anyhow::Result<_>>()?;
+
             let walker = if self.config.recursive {
                 WalkDir::new(input)
             } else {
                 WalkDir::new(input).max_depth(1)
-            };
-            
+            }.follow_links(self.config.follow_symlinks);
+
             for entry in walker.into_iter().filter_map(|e| e.ok()) {
                 let path = entry.path();
-                
-                if path.is_file() {
-                    // Check extension
-                    if let Some(ext) = path.extension().and_then(|e| e.to_str()) {
-                        if self.config.extensions.contains(&ext.to_lowercase()) {
-                            let size = fs::metadata(path)?.len();
-                            files.push((path.to_path_buf(), size));
-                            self.stats.add_file(size);
-                        }
-                    }
+
+                if !path.is_file() {
+                    continue;
+                }
+
+                // Check extension; for a compressed file like "rockyou.txt.gz" the
+                // configured extension (e.g. "txt") is matched against what's left
+                // after stripping the compression suffix.
+                let matched_path = if Compression::from_extension(path) == Compression::None {
+                    path
+                } else {
+                    path.file_stem().map(Path::new).unwrap_or(path)
+                };
+                let Some(ext) = matched_path.extension().and_then(|e| e.to_str()) else {
+                    continue;
+                };
+                if !self.config.extensions.contains(&ext.to_lowercase()) {
+                    continue;
+                }
+
+                let relative = path.strip_prefix(input).unwrap_or(path);
+                if !include.is_empty() && !include.iter().any(|p| p.matches_path(relative)) {
+                    continue;
+                }
+                if exclude.iter().any(|p| p.matches_path(relative)) {
+                    continue;
+                }
+
+                let size = fs::metadata(path)?.len();
+                if self.config.min_size.is_some_and(|min| size < min) {
+                    continue;
+                }
+                if self.config.max_size.is_some_and(|max| size > max) {
+                    continue;
                 }
+
+                files.push((path.to_path_buf(), size));
+                self.stats.add_file(size);
             }
         } else {
             anyhow::bail!("Input path does not exist: {:?}", input);
         }
-        
+
         Ok(files)
     }
     
-    /// Process files with single output file
+    /// Process files with single output file, in parallel across the rayon thread pool
     fn process_single_output(&self, files: &[(PathBuf, u64)]) -> anyhow::Result<()> {
-        let output_path = self.config.output_dir.join(&self.config.output_name);
-        
+        let output_path = crate::compress::append_extension(
+            &self.config.output_dir.join(&self.config.output_name),
+            self.config.compress,
+        );
+
         if !self.config.quiet {
             print_header("Processing (single output mode)...");
             print_info(&format!("Output: {:?}", output_path));
         }
-        
-        let filter = FilterConfig::new(
+
+        let mut filter = FilterConfig::new(
             self.config.lengths.clone(),
             self.config.pattern.as_deref(),
         )?;
-        
+        if let Some(ref deny) = self.config.deny_list {
+            filter = filter.with_deny_list(deny, self.config.substring_case_insensitive);
+        }
+        if let Some(ref allow) = self.config.allow_list {
+            filter = filter.with_allow_list(allow, self.config.substring_case_insensitive);
+        }
+
         let dedup: Box<dyn Deduplicator> = if self.config.no_dedup {
             Box::new(NoOpDeduplicator::new())
         } else {
-            // Estimate unique words based on total size
-            let total_size: u64 = files.iter().map(|(_, s)| *s).sum();
+            // Estimate unique words based on total (uncompressed-equivalent) size
+            let total_size = estimate_uncompressed_size(files);
             let estimated_words = (total_size / 10) as usize; // ~10 bytes per word avg
-            Box::new(ShardedDeduplicator::with_capacity(
-                num_cpus::get() * 4,
-                estimated_words / (num_cpus::get() * 4),
-            ))
+            self.build_deduplicator(estimated_words, None)?
         };
-        
-        let output = SingleOutputManager::new(output_path.clone(), self.config.buffer_size)?;
-        
-        // Process files
+        let dedup = self.wrap_normalized(dedup);
+
+        // With --sort, lines are buffered and spilled into sorted runs by an
+        // ExternalSorter instead of going straight to the output file; the
+        // final sorted, deduplicated result is written once all input has
+        // been consumed. Without it, lines stream straight to disk.
+        let output = if self.config.sort_output {
+            None
+        } else {
+            Some(SingleOutputManager::new(output_path.clone(), self.config.buffer_size, self.config.compress)?)
+        };
+        let sorter = if self.config.sort_output {
+            Some(ExternalSorter::new(self.config.tempdir.clone(), self.config.buffer_size))
+        } else {
+            None
+        };
+
+        // Process files concurrently across the rayon thread pool; each file
+        // streams into the shared, mutex-guarded output writer (or sorter).
         let total_bytes: u64 = files.iter().map(|(_, s)| *s).sum();
         let pb = if self.config.quiet {
             indicatif::ProgressBar::hidden()
         } else {
             create_bytes_progress_bar(total_bytes, "Processing...")
         };
-        
-        for (path, size) in files {
+
+        files.par_iter().try_for_each(|(path, size)| -> anyhow::Result<()> {
             if self.config.verbose {
                 pb.set_message(format!("Processing {:?}...", path.file_name().unwrap_or_default()));
             }
-            
-            self.process_file(&path, &filter, &*dedup, |line| {
-                output.write_line(line).ok();
+
+            self.process_file(path, &filter, &*dedup, |line| {
+                if let Some(ref output) = output {
+                    output.write_line(line).ok();
+                } else if let Some(ref sorter) = sorter {
+                    sorter.push(line).ok();
+                }
             })?;
-            
+
             pb.inc(*size);
             self.stats.complete_file(*size);
-        }
-        
+            Ok(())
+        })?;
+
         pb.finish_with_message("Complete".green().to_string());
-        output.flush()?;
-        
+
+        let lines_written = if let Some(sorter) = sorter {
+            sorter.finish(&output_path)?
+        } else {
+            let output = output.unwrap();
+            output.flush()?;
+            output.lines_written()
+        };
+
+        self.stats.record_output_file(
+            self.config.output_name.clone(),
+            output_path.to_string_lossy(),
+            lines_written,
+        );
+
         if !self.config.quiet {
             print_success(&format!("Output written to: {:?}", output_path));
-            print_info(&format!("Unique words: {}", output.lines_written()));
+            print_info(&format!("Unique words: {}", lines_written));
         }
-        
+
         Ok(())
     }
-    
-    /// Process files with single length filter
+
+    /// Process files with single length filter, in parallel across the rayon thread pool
     fn process_single_length(&self, files: &[(PathBuf, u64)], length: usize) -> anyhow::Result<()> {
         let output_name = format!("wordlist_len{}.txt", length);
-        let output_path = self.config.output_dir.join(&output_name);
-        
+        let output_path = crate::compress::append_extension(
+            &self.config.output_dir.join(&output_name),
+            self.config.compress,
+        );
+
         if !self.config.quiet {
             print_header(&format!("Processing (length {} filter)...", length));
             print_info(&format!("Output: {:?}", output_path));
         }
-        
+
         let filter = SingleLengthFilter::new(length, self.config.pattern.as_deref())?;
-        
+
         let dedup: Box<dyn Deduplicator> = if self.config.no_dedup {
             Box::new(NoOpDeduplicator::new())
         } else {
-            let total_size: u64 = files.iter().map(|(_, s)| *s).sum();
+            let total_size = estimate_uncompressed_size(files);
             let estimated_words = (total_size / 10) as usize;
-            Box::new(ShardedDeduplicator::with_capacity(
-                num_cpus::get() * 4,
-                estimated_words / (num_cpus::get() * 4),
-            ))
+            self.build_deduplicator(estimated_words, None)?
         };
-        
-        let output = SingleOutputManager::new(output_path.clone(), self.config.buffer_size)?;
-        
+        let dedup = self.wrap_normalized(dedup);
+
+        let output = if self.config.sort_output {
+            None
+        } else {
+            Some(SingleOutputManager::new(output_path.clone(), self.config.buffer_size, self.config.compress)?)
+        };
+        let sorter = if self.config.sort_output {
+            Some(ExternalSorter::new(self.config.tempdir.clone(), self.config.buffer_size))
+        } else {
+            None
+        };
+
         let total_bytes: u64 = files.iter().map(|(_, s)| *s).sum();
         let pb = if self.config.quiet {
             indicatif::ProgressBar::hidden()
         } else {
             create_bytes_progress_bar(total_bytes, "Processing...")
         };
-        
-        for (path, size) in files {
+
+        files.par_iter().try_for_each(|(path, size)| -> anyhow::Result<()> {
             if self.config.verbose {
                 pb.set_message(format!("Processing {:?}...", path.file_name().unwrap_or_default()));
             }
-            
-            self.process_file_single_length(&path, &filter, &*dedup, |line| {
-                output.write_line(line).ok();
+
+            self.process_file_single_length(path, &filter, &*dedup, |line| {
+                if let Some(ref output) = output {
+                    output.write_line(line).ok();
+                } else if let Some(ref sorter) = sorter {
+                    sorter.push(line).ok();
+                }
             })?;
-            
+
             pb.inc(*size);
             self.stats.complete_file(*size);
-        }
-        
+            Ok(())
+        })?;
+
         pb.finish_with_message("Complete".green().to_string());
-        output.flush()?;
-        
+
+        let lines_written = if let Some(sorter) = sorter {
+            sorter.finish(&output_path)?
+        } else {
+            let output = output.unwrap();
+            output.flush()?;
+            output.lines_written()
+        };
+
+        self.stats.record_output_file(
+            output_name,
+            output_path.to_string_lossy(),
+            lines_written,
+        );
+
         if !self.config.quiet {
             print_success(&format!("Output written to: {:?}", output_path));
-            print_info(&format!("Unique words: {}", output.lines_written()));
+            print_info(&format!("Unique words: {}", lines_written));
         }
-        
+
         Ok(())
     }
-    
-    /// Process files with multiple length filters
+
+    /// Process files with multiple length filters, in parallel across the rayon thread pool
     fn process_multi_length(&self, files: &[(PathBuf, u64)], lengths: &[usize]) -> anyhow::Result<()> {
         if !self.config.quiet {
             print_header(&format!("Processing (lengths {:?})...", lengths));
@@ -284,67 +508,189 @@ impl Processor {
         
         let router = MultiLengthRouter::new(lengths.to_vec(), self.config.pattern.as_deref())?;
         
-        // Create deduplicator per length
+        // Create deduplicator per length, one `--disk-dedup-path` suffix each
+        // so a `--dedup-strategy disk` run doesn't point every length's
+        // deduplicator at the same on-disk database.
         let dedups: Vec<Box<dyn Deduplicator>> = if self.config.no_dedup {
             lengths.iter().map(|_| Box::new(NoOpDeduplicator::new()) as Box<dyn Deduplicator>).collect()
         } else {
-            let total_size: u64 = files.iter().map(|(_, s)| *s).sum();
+            let total_size = estimate_uncompressed_size(files);
             let estimated_per_length = (total_size / 10 / lengths.len() as u64) as usize;
-            lengths.iter().map(|_| {
-                Box::new(ShardedDeduplicator::with_capacity(
-                    num_cpus::get() * 2,
-                    estimated_per_length / (num_cpus::get() * 2),
-                )) as Box<dyn Deduplicator>
-            }).collect()
+            lengths.iter()
+                .map(|length| self.build_deduplicator(estimated_per_length, Some(&length.to_string())))
+                .collect::<anyhow::Result<Vec<_>>>()?
         };
-        
-        // Create output manager
-        let mut output = MultiOutputManager::new(
-            self.config.output_dir.clone(),
-            "wordlist",
-            self.config.buffer_size,
-        );
-        output.init_lengths(lengths)?;
-        
+        let dedups: Vec<Box<dyn Deduplicator>> = dedups.into_iter().map(|d| self.wrap_normalized(d)).collect();
+
+        // One output sink per length: either a plain buffered writer, or (with
+        // --sort) an ExternalSorter that spills sorted runs and merges them
+        // into the final per-length file once processing completes.
+        let mut output = if self.config.sort_output {
+            None
+        } else {
+            let mut manager = MultiOutputManager::new(
+                self.config.output_dir.clone(),
+                "wordlist",
+                self.config.buffer_size,
+                self.config.compress,
+            );
+            manager.init_lengths(lengths)?;
+            Some(manager)
+        };
+        let sorters: Option<Vec<ExternalSorter>> = if self.config.sort_output {
+            Some(lengths.iter()
+                .map(|_| ExternalSorter::new(self.config.tempdir.clone(), self.config.buffer_size))
+                .collect())
+        } else {
+            None
+        };
+
         let total_bytes: u64 = files.iter().map(|(_, s)| *s).sum();
         let pb = if self.config.quiet {
             indicatif::ProgressBar::hidden()
         } else {
             create_bytes_progress_bar(total_bytes, "Processing...")
         };
-        
-        for (path, size) in files {
+
+        files.par_iter().try_for_each(|(path, size)| -> anyhow::Result<()> {
             if self.config.verbose {
                 pb.set_message(format!("Processing {:?}...", path.file_name().unwrap_or_default()));
             }
-            
-            self.process_file_multi_length(&path, &router, &dedups, &output)?;
-            
+
+            self.process_file_multi_length(path, &router, &dedups, |idx, line| {
+                if let Some(ref output) = output {
+                    let length = router.lengths()[idx];
+                    output.write_line(line, length).ok();
+                } else if let Some(ref sorters) = sorters {
+                    sorters[idx].push(line).ok();
+                }
+            })?;
+
             pb.inc(*size);
             self.stats.complete_file(*size);
+            Ok(())
+        })?;
+
+        pb.finish_with_message("Complete".green().to_string());
+
+        let stats: Vec<(usize, PathBuf, u64)> = if let Some(sorters) = sorters {
+            sorters.into_iter().zip(lengths.iter()).map(|(sorter, &length)| {
+                let path = self.config.output_dir.join(format!("wordlist_len{}.txt", length));
+                let written = sorter.finish(&path)?;
+                Ok::<_, anyhow::Error>((length, path, written))
+            }).collect::<anyhow::Result<Vec<_>>>()?
+        } else {
+            let output = output.take().unwrap();
+            output.flush_all()?;
+            output.get_paths().into_iter()
+                .map(|(len, path)| {
+                    let lines = output.get_stats().iter()
+                        .find(|(l, _, _)| *l == len)
+                        .map(|(_, lines_written, _)| *lines_written)
+                        .unwrap_or(0);
+                    (len, path, lines)
+                })
+                .collect()
+        };
+
+        for (len, path, words) in &stats {
+            self.stats.record_output_file(
+                format!("wordlist_len{}.txt", len),
+                path.to_string_lossy(),
+                *words,
+            );
         }
-        
+
+        if !self.config.quiet {
+            print_success("Output files created:");
+            for (len, path, words) in &stats {
+                print_bullet(&format!("Length {}: {:?} ({} words)", len, path, words));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Process files, demultiplexing words into one file per matching
+    /// builtin category in a single pass (`--categorize`).
+    ///
+    /// Unlike `process_multi_length`'s one-bucket-per-word routing, a word
+    /// can land in several category files at once here -- e.g. a word can be
+    /// both `alphanumeric` and `common_password` -- since
+    /// [`PatternSetFilter::which_matches`] returns every matching index
+    /// instead of the first.
+    fn process_pattern_set(&self, files: &[(PathBuf, u64)]) -> anyhow::Result<()> {
+        if !self.config.quiet {
+            print_header("Processing (categorize)...");
+        }
+
+        let named = crate::filter::patterns::NAMED;
+        let pattern_set = PatternSetFilter::new(named, SetCombinator::Any)?;
+        let names: Vec<String> = named.iter().map(|(name, _)| name.to_string()).collect();
+
+        // Create deduplicator per category, one `--disk-dedup-path` suffix
+        // each so a `--dedup-strategy disk` run doesn't point every
+        // category's deduplicator at the same on-disk database.
+        let dedups: Vec<Box<dyn Deduplicator>> = if self.config.no_dedup {
+            names.iter().map(|_| Box::new(NoOpDeduplicator::new()) as Box<dyn Deduplicator>).collect()
+        } else {
+            let total_size = estimate_uncompressed_size(files);
+            let estimated_per_category = (total_size / 10) as usize;
+            names.iter()
+                .map(|name| self.build_deduplicator(estimated_per_category, Some(name)))
+                .collect::<anyhow::Result<Vec<_>>>()?
+        };
+        let dedups: Vec<Box<dyn Deduplicator>> = dedups.into_iter().map(|d| self.wrap_normalized(d)).collect();
+
+        let mut output = PatternOutputManager::new(self.config.output_dir.clone(), self.config.buffer_size, self.config.compress);
+        output.init_categories(&names)?;
+
+        let total_bytes: u64 = files.iter().map(|(_, s)| *s).sum();
+        let pb = if self.config.quiet {
+            indicatif::ProgressBar::hidden()
+        } else {
+            create_bytes_progress_bar(total_bytes, "Processing...")
+        };
+
+        files.par_iter().try_for_each(|(path, size)| -> anyhow::Result<()> {
+            if self.config.verbose {
+                pb.set_message(format!("Processing {:?}...", path.file_name().unwrap_or_default()));
+            }
+
+            self.process_file_pattern_set(path, &pattern_set, &dedups, |idx, line| {
+                output.write_line(line, &names[idx]).ok();
+            })?;
+
+            pb.inc(*size);
+            self.stats.complete_file(*size);
+            Ok(())
+        })?;
+
         pb.finish_with_message("Complete".green().to_string());
         output.flush_all()?;
-        
+
+        let paths: std::collections::HashMap<String, PathBuf> = output.get_paths().into_iter().collect();
+        for (name, lines, _) in output.get_stats() {
+            let path = paths.get(&name).cloned().unwrap_or_default();
+            self.stats.record_output_file(name, path.to_string_lossy(), lines);
+        }
+
         if !self.config.quiet {
             print_success("Output files created:");
-            for (len, path) in output.get_paths() {
-                if let Some(writer_stats) = output.get_stats().iter().find(|(l, _, _)| *l == len) {
-                    print_bullet(&format!("Length {}: {:?} ({} words)", len, path, writer_stats.1));
-                }
+            for (name, lines, _) in output.get_stats() {
+                print_bullet(&format!("{}: {} words", name, lines));
             }
         }
-        
+
         Ok(())
     }
-    
+
     /// Process a single file with generic filter
     fn process_file<F>(&self, path: &Path, filter: &FilterConfig, dedup: &dyn Deduplicator, mut writer: F) -> anyhow::Result<()>
     where
         F: FnMut(&str),
     {
-        let iter = MmapLineIterator::new(path)?;
+        let iter = open_line_iterator(path)?;
         
         for line_result in iter {
             match line_result {
@@ -380,7 +726,7 @@ impl Processor {
     where
         F: FnMut(&str),
     {
-        let iter = MmapLineIterator::new(path)?;
+        let iter = open_line_iterator(path)?;
         
         for line_result in iter {
             match line_result {
@@ -412,15 +758,18 @@ impl Processor {
     }
     
     /// Process file with multi-length routing
-    fn process_file_multi_length(
+    fn process_file_multi_length<F>(
         &self,
         path: &Path,
         router: &MultiLengthRouter,
         dedups: &[Box<dyn Deduplicator>],
-        output: &MultiOutputManager,
-    ) -> anyhow::Result<()> {
-        let iter = MmapLineIterator::new(path)?;
-        
+        mut writer: F,
+    ) -> anyhow::Result<()>
+    where
+        F: FnMut(usize, &str),
+    {
+        let iter = open_line_iterator(path)?;
+
         for line_result in iter {
             match line_result {
                 Ok(line) => {
@@ -428,15 +777,14 @@ impl Processor {
                     if line.is_empty() {
                         continue;
                     }
-                    
+
                     self.stats.add_line();
-                    
+
                     if let Some(idx) = router.route(line) {
                         self.stats.add_match();
-                        
+
                         if dedups[idx].insert(line) {
-                            let length = router.lengths()[idx];
-                            output.write_line(line, length)?;
+                            writer(idx, line);
                         } else {
                             self.stats.add_duplicate();
                         }
@@ -447,10 +795,56 @@ impl Processor {
                 }
             }
         }
-        
+
         Ok(())
     }
-    
+
+    /// Process file with pattern-set categorization; a line can be written to
+    /// more than one bucket if it matches more than one category.
+    fn process_file_pattern_set<F>(
+        &self,
+        path: &Path,
+        pattern_set: &PatternSetFilter,
+        dedups: &[Box<dyn Deduplicator>],
+        mut writer: F,
+    ) -> anyhow::Result<()>
+    where
+        F: FnMut(usize, &str),
+    {
+        let iter = open_line_iterator(path)?;
+
+        for line_result in iter {
+            match line_result {
+                Ok(line) => {
+                    let line = line.trim();
+                    if line.is_empty() {
+                        continue;
+                    }
+
+                    self.stats.add_line();
+
+                    let matches = pattern_set.which_matches(line);
+                    if !matches.is_empty() {
+                        self.stats.add_match();
+
+                        for idx in matches {
+                            if dedups[idx].insert(line) {
+                                writer(idx, line);
+                            } else {
+                                self.stats.add_duplicate();
+                            }
+                        }
+                    }
+                }
+                Err(_) => {
+                    self.stats.add_error();
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     /// Dry run report
     fn dry_run_report(&self, files: &[(PathBuf, u64)]) -> anyhow::Result<()> {
         print_header("DRY RUN - No files will be written");
@@ -463,7 +857,11 @@ impl Processor {
         println!("\n  {} Output configuration:", "▶".green());
         print_bullet(&format!("Output directory: {:?}", self.config.output_dir));
         
-        if self.config.single_file {
+        if self.config.categorize {
+            for (name, _) in crate::filter::patterns::NAMED.iter() {
+                print_bullet(&format!("{}.txt", name));
+            }
+        } else if self.config.single_file {
             print_bullet(&format!("Single output file: {}", self.config.output_name));
         } else if let Some(ref lengths) = self.config.lengths {
             for len in lengths {
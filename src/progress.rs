@@ -5,8 +5,9 @@
 use bytesize::ByteSize;
 use colored::*;
 use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
-use std::sync::atomic::{AtomicU64, Ordering};
-use std::sync::Arc;
+use serde::Serialize;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 
 /// Color theme for the tool
@@ -130,6 +131,17 @@ pub fn create_bytes_progress_bar(total_bytes: u64, msg: &str) -> ProgressBar {
     pb
 }
 
+/// One output file produced by a processing run, recorded for `--json`
+/// summaries. Carries only what's tracked uniformly across every output
+/// path (single-file, per-length, per-category) -- per-file byte counts
+/// aren't, so they're omitted rather than faked.
+#[derive(Debug, Clone, Serialize)]
+pub struct OutputFileSummary {
+    pub name: String,
+    pub path: String,
+    pub lines: u64,
+}
+
 /// Processing statistics
 #[derive(Debug)]
 pub struct ProcessingStats {
@@ -141,6 +153,7 @@ pub struct ProcessingStats {
     pub matched_lines: AtomicU64,
     pub duplicate_lines: AtomicU64,
     pub error_lines: AtomicU64,
+    pub output_files: Mutex<Vec<OutputFileSummary>>,
     pub start_time: Instant,
 }
 
@@ -155,6 +168,7 @@ impl ProcessingStats {
             matched_lines: AtomicU64::new(0),
             duplicate_lines: AtomicU64::new(0),
             error_lines: AtomicU64::new(0),
+            output_files: Mutex::new(Vec::new()),
             start_time: Instant::now(),
         }
     }
@@ -228,7 +242,17 @@ impl ProcessingStats {
     pub fn get_error_lines(&self) -> u64 {
         self.error_lines.load(Ordering::Relaxed)
     }
-    
+
+    /// Record one output file produced by this run, for inclusion in the
+    /// `--json` summary.
+    pub fn record_output_file(&self, name: impl Into<String>, path: impl Into<String>, lines: u64) {
+        self.output_files.lock().unwrap().push(OutputFileSummary {
+            name: name.into(),
+            path: path.into(),
+            lines,
+        });
+    }
+
     pub fn elapsed(&self) -> Duration {
         self.start_time.elapsed()
     }
@@ -297,6 +321,41 @@ impl ProcessingStats {
         println!();
         println!("{}", "═".repeat(60).green());
     }
+
+    /// Build the `--json` summary as a `serde_json::Value`. Atomics aren't
+    /// `Serialize`, so the value is assembled field-by-field with `json!`
+    /// rather than deriving it on this struct.
+    pub fn to_json(&self) -> serde_json::Value {
+        let matched = self.get_matched_lines();
+        let duplicates = self.get_duplicate_lines();
+        serde_json::json!({
+            "files": {
+                "processed": self.get_processed_files(),
+                "total": self.get_total_files(),
+            },
+            "bytes": {
+                "processed": self.get_processed_bytes(),
+                "total": self.get_total_bytes(),
+            },
+            "lines": {
+                "total": self.get_total_lines(),
+                "matched": matched,
+                "duplicates": duplicates,
+                "unique": matched.saturating_sub(duplicates),
+                "errors": self.get_error_lines(),
+            },
+            "duration_secs": self.elapsed().as_secs_f64(),
+            "lines_per_second": self.lines_per_second(),
+            "bytes_per_second": self.bytes_per_second(),
+            "output_files": *self.output_files.lock().unwrap(),
+        })
+    }
+
+    /// Print the final statistics as a single line of JSON to stdout,
+    /// for `--json` mode.
+    pub fn print_summary_json(&self) {
+        println!("{}", self.to_json());
+    }
 }
 
 impl Default for ProcessingStats {
@@ -321,6 +380,74 @@ fn format_number(n: u64) -> String {
     result
 }
 
+/// Set by the `SIGUSR1`/`SIGINFO` handler; cleared by the watcher thread
+/// once it's printed a snapshot. The handler only ever touches this flag --
+/// all the actual work (reading stats, formatting, printing) happens on the
+/// watcher thread, since none of that is async-signal-safe.
+#[cfg(unix)]
+static SNAPSHOT_REQUESTED: AtomicBool = AtomicBool::new(false);
+
+#[cfg(unix)]
+extern "C" fn request_snapshot(_signum: libc::c_int) {
+    SNAPSHOT_REQUESTED.store(true, Ordering::SeqCst);
+}
+
+/// Install the `SIGUSR1` (and, on BSD/macOS, `SIGINFO`) handler that flags a
+/// pending snapshot request. A no-op on platforms without these signals.
+#[cfg(unix)]
+fn install_snapshot_signal_handler() {
+    unsafe {
+        libc::signal(libc::SIGUSR1, request_snapshot as usize);
+
+        #[cfg(any(
+            target_os = "macos",
+            target_os = "freebsd",
+            target_os = "netbsd",
+            target_os = "openbsd",
+            target_os = "dragonfly"
+        ))]
+        libc::signal(libc::SIGINFO, request_snapshot as usize);
+    }
+}
+
+#[cfg(not(unix))]
+fn install_snapshot_signal_handler() {}
+
+/// Print a one-line progress snapshot to stderr, mirroring `dd`'s
+/// progress-on-signal behavior for long multi-hour runs.
+fn print_stats_snapshot(stats: &ProcessingStats) {
+    eprintln!(
+        "{} {}/{} processed | matched {} | dup {} | {:.0} lines/s | {}/s",
+        "[snapshot]".cyan(),
+        ByteSize(stats.get_processed_bytes()),
+        ByteSize(stats.get_total_bytes()),
+        format_number(stats.get_matched_lines()),
+        format_number(stats.get_duplicate_lines()),
+        stats.lines_per_second(),
+        ByteSize(stats.bytes_per_second() as u64),
+    );
+}
+
+/// Install the snapshot signal handler and spawn the watcher thread that
+/// polls for it. Unix-only; a no-op elsewhere. `pub(crate)` so the real
+/// processing entry point (`Processor::new`), not just `ProgressManager`,
+/// can arm the `SIGUSR1`/`SIGINFO` snapshot for the `Arc<ProcessingStats>`
+/// it actually uses during a run.
+#[cfg(unix)]
+pub(crate) fn spawn_snapshot_watcher(stats: Arc<ProcessingStats>) {
+    install_snapshot_signal_handler();
+
+    std::thread::spawn(move || loop {
+        std::thread::sleep(Duration::from_millis(200));
+        if SNAPSHOT_REQUESTED.swap(false, Ordering::SeqCst) {
+            print_stats_snapshot(&stats);
+        }
+    });
+}
+
+#[cfg(not(unix))]
+pub(crate) fn spawn_snapshot_watcher(_stats: Arc<ProcessingStats>) {}
+
 /// Progress manager for multi-file processing
 pub struct ProgressManager {
     multi: MultiProgress,
@@ -333,7 +460,8 @@ impl ProgressManager {
     pub fn new(total_bytes: u64, quiet: bool) -> Self {
         let multi = MultiProgress::new();
         let stats = Arc::new(ProcessingStats::new());
-        
+        spawn_snapshot_watcher(Arc::clone(&stats));
+
         let main_bar = if quiet {
             ProgressBar::hidden()
         } else {
@@ -0,0 +1,257 @@
+//! External merge sort for producing sorted output without holding the
+//! full result set in memory.
+//!
+//! Lines are buffered in memory as they arrive; once the buffer grows past
+//! a configured threshold it is sorted in place and flushed to a "run" file
+//! in a temp directory (modeled on GNU parallel's spill-to-tempdir design).
+//! Once input is exhausted, all runs are merged with a k-way merge driven
+//! by a `BinaryHeap`, folding deduplication into the merge step by skipping
+//! lines equal to the previously emitted one.
+
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering as AtomicOrdering};
+use std::sync::Mutex;
+
+/// Process-wide counter handing out a unique id to each `ExternalSorter`,
+/// so sorters spilling into the same `tempdir` concurrently (one per
+/// `--length` value, say) never pick the same run filename.
+static NEXT_SORTER_ID: AtomicU64 = AtomicU64::new(0);
+
+/// Accumulates lines in memory, spilling sorted runs to disk once the
+/// buffer exceeds `buffer_size` bytes, and merges all runs into a single
+/// sorted, deduplicated output file on [`finish`](Self::finish).
+pub struct ExternalSorter {
+    tempdir: PathBuf,
+    buffer_size: usize,
+    sorter_id: u64,
+    run_id: AtomicU64,
+    state: Mutex<SorterState>,
+}
+
+struct SorterState {
+    buffer: Vec<String>,
+    buffer_bytes: usize,
+    runs: Vec<PathBuf>,
+}
+
+impl ExternalSorter {
+    /// Create a new sorter that spills run files into `tempdir` once the
+    /// in-memory buffer reaches roughly `buffer_size` bytes.
+    pub fn new(tempdir: PathBuf, buffer_size: usize) -> Self {
+        Self {
+            tempdir,
+            buffer_size,
+            sorter_id: NEXT_SORTER_ID.fetch_add(1, AtomicOrdering::Relaxed),
+            run_id: AtomicU64::new(0),
+            state: Mutex::new(SorterState {
+                buffer: Vec::new(),
+                buffer_bytes: 0,
+                runs: Vec::new(),
+            }),
+        }
+    }
+
+    /// Add a line, spilling a sorted run to disk if the buffer has grown
+    /// past the configured threshold.
+    pub fn push(&self, line: &str) -> anyhow::Result<()> {
+        let mut state = self.state.lock().unwrap();
+        state.buffer_bytes += line.len() + 1;
+        state.buffer.push(line.to_string());
+
+        if state.buffer_bytes >= self.buffer_size {
+            self.spill(&mut state)?;
+        }
+
+        Ok(())
+    }
+
+    /// Sort and flush the current buffer to a new run file, if non-empty.
+    fn spill(&self, state: &mut SorterState) -> anyhow::Result<()> {
+        if state.buffer.is_empty() {
+            return Ok(());
+        }
+
+        state.buffer.sort_unstable();
+
+        let run_id = self.run_id.fetch_add(1, AtomicOrdering::Relaxed);
+        let run_path = self.tempdir.join(format!(
+            "wordlist-filter-{}-{}-{}.run",
+            std::process::id(),
+            self.sorter_id,
+            run_id
+        ));
+
+        let file = File::create(&run_path)?;
+        let mut writer = BufWriter::new(file);
+        for line in state.buffer.drain(..) {
+            writeln!(writer, "{}", line)?;
+        }
+        writer.flush()?;
+
+        state.runs.push(run_path);
+        state.buffer_bytes = 0;
+
+        Ok(())
+    }
+
+    /// Finish accumulating, merge all runs (spilling any remaining buffered
+    /// lines first) and write the sorted, deduplicated result to `output`.
+    /// Run files are removed once the merge completes. Returns the number
+    /// of unique lines written.
+    pub fn finish(self, output: &Path) -> anyhow::Result<u64> {
+        let mut state = self.state.into_inner().unwrap();
+
+        // Common case: everything fit in one buffer, no run files to merge.
+        if state.runs.is_empty() {
+            state.buffer.sort_unstable();
+            state.buffer.dedup();
+
+            let file = File::create(output)?;
+            let mut writer = BufWriter::new(file);
+            for line in &state.buffer {
+                writeln!(writer, "{}", line)?;
+            }
+            writer.flush()?;
+
+            return Ok(state.buffer.len() as u64);
+        }
+
+        self.spill(&mut state)?;
+        let written = merge_runs(&state.runs, output)?;
+
+        for run in &state.runs {
+            let _ = std::fs::remove_file(run);
+        }
+
+        Ok(written)
+    }
+}
+
+/// One pending line from a single run, paired with the run's index so a
+/// heap pop knows which reader to advance next.
+struct RunEntry {
+    line: String,
+    run_index: usize,
+}
+
+impl PartialEq for RunEntry {
+    fn eq(&self, other: &Self) -> bool {
+        self.line == other.line
+    }
+}
+
+impl Eq for RunEntry {}
+
+impl PartialOrd for RunEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for RunEntry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // BinaryHeap is a max-heap; reverse the comparison so the smallest
+        // line is popped first.
+        other.line.cmp(&self.line)
+    }
+}
+
+/// K-way merge of sorted run files into `output`, popping the smallest
+/// pending line from a `BinaryHeap` and advancing that run's reader.
+/// Deduplication is folded into the merge by skipping lines equal to the
+/// previously emitted one. Returns the number of unique lines written.
+fn merge_runs(runs: &[PathBuf], output: &Path) -> anyhow::Result<u64> {
+    let mut readers: Vec<BufReader<File>> = runs
+        .iter()
+        .map(|p| Ok(BufReader::new(File::open(p)?)))
+        .collect::<anyhow::Result<_>>()?;
+
+    let mut heap = BinaryHeap::with_capacity(readers.len());
+    for (run_index, reader) in readers.iter_mut().enumerate() {
+        if let Some(line) = read_line(reader)? {
+            heap.push(RunEntry { line, run_index });
+        }
+    }
+
+    let out_file = File::create(output)?;
+    let mut writer = BufWriter::new(out_file);
+    let mut written: u64 = 0;
+    let mut last_emitted: Option<String> = None;
+
+    while let Some(RunEntry { line, run_index }) = heap.pop() {
+        if last_emitted.as_deref() != Some(line.as_str()) {
+            writeln!(writer, "{}", line)?;
+            written += 1;
+            last_emitted = Some(line.clone());
+        }
+
+        if let Some(next_line) = read_line(&mut readers[run_index])? {
+            heap.push(RunEntry { line: next_line, run_index });
+        }
+    }
+
+    writer.flush()?;
+    Ok(written)
+}
+
+/// Read a single line from `reader`, stripping the trailing newline.
+/// Returns `None` at EOF.
+fn read_line(reader: &mut BufReader<File>) -> anyhow::Result<Option<String>> {
+    let mut buf = String::new();
+    let bytes = reader.read_line(&mut buf)?;
+    if bytes == 0 {
+        return Ok(None);
+    }
+
+    while buf.ends_with('\n') || buf.ends_with('\r') {
+        buf.pop();
+    }
+
+    Ok(Some(buf))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_sorts_within_single_buffer() {
+        let temp_dir = TempDir::new().unwrap();
+        let sorter = ExternalSorter::new(temp_dir.path().to_path_buf(), 1024 * 1024);
+
+        for word in ["banana", "apple", "cherry", "apple"] {
+            sorter.push(word).unwrap();
+        }
+
+        let output = temp_dir.path().join("out.txt");
+        let written = sorter.finish(&output).unwrap();
+
+        let content = std::fs::read_to_string(&output).unwrap();
+        assert_eq!(content, "apple\nbanana\ncherry\n");
+        assert_eq!(written, 3);
+    }
+
+    #[test]
+    fn test_merges_multiple_runs() {
+        let temp_dir = TempDir::new().unwrap();
+        // Force a spill after every single line so each word becomes its
+        // own run file, exercising the k-way merge path.
+        let sorter = ExternalSorter::new(temp_dir.path().to_path_buf(), 1);
+
+        for word in ["delta", "alpha", "charlie", "bravo", "alpha"] {
+            sorter.push(word).unwrap();
+        }
+
+        let output = temp_dir.path().join("out.txt");
+        let written = sorter.finish(&output).unwrap();
+
+        let content = std::fs::read_to_string(&output).unwrap();
+        assert_eq!(content, "alpha\nbravo\ncharlie\ndelta\n");
+        assert_eq!(written, 4);
+    }
+}